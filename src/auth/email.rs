@@ -0,0 +1,65 @@
+use crate::domain::{
+    EmailVerificationClaims, EmailVerificationSigner as EmailVerificationSignerTrait, Error,
+};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use serde::{Deserialize, Serialize};
+use time::Duration;
+
+const AUDIENCE: &str = "email_verification";
+
+#[derive(Serialize, Deserialize, Clone)]
+struct Claims {
+    exp: usize,
+    aud: String,
+    sub: String,
+    email: String,
+}
+
+pub struct EmailVerificationSigner {
+    duration: Duration,
+    secret: String,
+}
+
+impl EmailVerificationSigner {
+    pub fn new(duration: Duration, secret: String) -> Self {
+        Self { duration, secret }
+    }
+}
+
+impl EmailVerificationSignerTrait for EmailVerificationSigner {
+    fn sign(&self, claims: &EmailVerificationClaims) -> Result<String, Error> {
+        let expires = time::OffsetDateTime::now_utc() + self.duration;
+        let claims = Claims {
+            exp: expires.unix_timestamp() as usize,
+            aud: AUDIENCE.to_string(),
+            sub: claims.account_id.to_string(),
+            email: claims.email.clone(),
+        };
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(self.secret.as_ref()),
+        )
+        .map_err(|err| Error::Unknown(err.to_string()))
+    }
+
+    fn verify(&self, token: String) -> Result<EmailVerificationClaims, Error> {
+        let mut validation = Validation::default();
+        validation.set_audience(&[AUDIENCE]);
+        let data = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(self.secret.as_bytes()),
+            &validation,
+        )
+        .map_err(|err| Error::Forbidden(err.to_string()))?;
+        let account_id = data
+            .claims
+            .sub
+            .parse()
+            .map_err(|_| Error::Forbidden("invalid subject".to_string()))?;
+        Ok(EmailVerificationClaims {
+            account_id,
+            email: data.claims.email,
+        })
+    }
+}
@@ -1,51 +1,167 @@
-use crate::domain::{AuthToken, AuthTokenGenerator as AuthTokenGeneratorTrait, Error};
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use std::sync::Arc;
+
+use crate::{
+    auth::KeyRing,
+    domain::{AuthToken, AuthTokenClaims, AuthTokenGenerator as AuthTokenGeneratorTrait, Error},
+};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use jsonwebtoken::{Header, Validation, decode, decode_header, encode};
 use serde::{Deserialize, Serialize};
 use time::Duration;
 
 #[derive(Serialize, Deserialize, Clone)]
 struct Claims {
     exp: usize,
+    iat: usize,
     sub: String,
+    jti: String,
+    sid: String,
+    #[serde(default)]
+    scope: String,
 }
 
 pub struct AuthTokenGenerator {
     duration: Duration,
-    secret: String,
+    keys: Arc<KeyRing>,
 }
 
 impl AuthTokenGenerator {
-    pub fn new(duration: Duration, secret: String) -> Self {
-        Self { duration, secret }
+    pub fn new(duration: Duration, keys: Arc<KeyRing>) -> Self {
+        Self { duration, keys }
     }
 }
 
+fn generate_token_id() -> String {
+    let mut bytes = [0u8; 16];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
 impl AuthTokenGeneratorTrait for AuthTokenGenerator {
-    fn generate(&self, account_id: i32) -> Result<AuthToken, Error> {
-        let expires = time::OffsetDateTime::now_utc() + self.duration;
+    fn generate(
+        &self,
+        account_id: i32,
+        session_id: String,
+        scopes: Vec<String>,
+    ) -> Result<AuthToken, Error> {
+        let issued_at = time::OffsetDateTime::now_utc();
+        let expires = issued_at + self.duration;
         let claims = Claims {
             exp: expires.unix_timestamp() as usize,
+            iat: issued_at.unix_timestamp() as usize,
             sub: account_id.to_string(),
+            jti: generate_token_id(),
+            sid: session_id,
+            scope: scopes.join(" "),
         };
-        let token = encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(self.secret.as_ref()),
-        )
-        .unwrap();
-        Ok(AuthToken {
-            token,
-            expires: expires,
+        let key = self.keys.signing_key();
+        let mut header = Header::new(key.algorithm);
+        header.kid = key.kid.clone();
+        let token = encode(&header, &claims, &key.encoding_key)
+            .map_err(|err| Error::Unknown(err.to_string()))?;
+        Ok(AuthToken { token, expires })
+    }
+
+    fn parse(&self, token: String) -> Result<AuthTokenClaims, Error> {
+        let header = decode_header(&token).map_err(|err| Error::Unauthorized(err.to_string()))?;
+        let key = self
+            .keys
+            .verification_key(header.kid.as_deref())
+            .ok_or_else(|| Error::Unauthorized("unknown signing key".to_string()))?;
+        let data = decode::<Claims>(token, &key.decoding_key, &Validation::new(key.algorithm))
+            .map_err(|err| Error::Unauthorized(err.to_string()))?;
+        Ok(AuthTokenClaims {
+            account_id: data
+                .claims
+                .sub
+                .parse()
+                .map_err(|_| Error::Unauthorized("invalid subject".to_string()))?,
+            token_id: data.claims.jti,
+            session_id: data.claims.sid,
+            scopes: data
+                .claims
+                .scope
+                .split_whitespace()
+                .map(str::to_string)
+                .collect(),
+            issued_at: into_offset_date_time(data.claims.iat)?,
+            expires: into_offset_date_time(data.claims.exp)?,
         })
     }
+}
+
+fn into_offset_date_time(timestamp: usize) -> Result<time::OffsetDateTime, Error> {
+    time::OffsetDateTime::from_unix_timestamp(timestamp as i64)
+        .map_err(|_| Error::Unauthorized("invalid timestamp".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::{Algorithm, EncodingKey};
+
+    const SECRET: &str = "test-secret";
+
+    fn generator(secret: &str) -> AuthTokenGenerator {
+        AuthTokenGenerator::new(Duration::minutes(5), Arc::new(KeyRing::hmac(secret)))
+    }
+
+    fn claims(sub: &str) -> Claims {
+        let now = time::OffsetDateTime::now_utc();
+        Claims {
+            exp: (now + Duration::minutes(5)).unix_timestamp() as usize,
+            iat: now.unix_timestamp() as usize,
+            sub: sub.to_string(),
+            jti: generate_token_id(),
+            sid: "session".to_string(),
+            scope: "todo:read".to_string(),
+        }
+    }
+
+    fn sign(header: &Header, claims: &Claims) -> String {
+        encode(header, claims, &EncodingKey::from_secret(SECRET.as_bytes())).unwrap()
+    }
+
+    #[test]
+    fn parses_generated_token() {
+        let generator = generator(SECRET);
+        let token = generator
+            .generate(42, "session".to_string(), vec!["todo:read".to_string()])
+            .unwrap();
+        let claims = generator.parse(token.token).unwrap();
+        assert_eq!(claims.account_id, 42);
+        assert_eq!(claims.scopes, vec!["todo:read".to_string()]);
+    }
+
+    #[test]
+    fn rejects_malformed_token() {
+        for token in ["", "not-a-jwt", "a.b.c", "a.b"] {
+            let result = generator(SECRET).parse(token.to_string());
+            assert!(matches!(result, Err(Error::Unauthorized(_))), "{}", token);
+        }
+    }
+
+    #[test]
+    fn rejects_bad_signature() {
+        let token = generator("other-secret")
+            .generate(42, "session".to_string(), Vec::new())
+            .unwrap();
+        let result = generator(SECRET).parse(token.token);
+        assert!(matches!(result, Err(Error::Unauthorized(_))));
+    }
+
+    #[test]
+    fn rejects_unknown_kid() {
+        let mut header = Header::new(Algorithm::HS256);
+        header.kid = Some("unknown".to_string());
+        let result = generator(SECRET).parse(sign(&header, &claims("42")));
+        assert!(matches!(result, Err(Error::Unauthorized(_))));
+    }
 
-    fn parse(&self, token: String) -> Result<i32, Error> {
-        let data = decode::<Claims>(
-            token,
-            &DecodingKey::from_secret(self.secret.as_bytes()),
-            &Validation::default(),
-        )
-        .unwrap();
-        Ok(data.claims.sub.parse().unwrap())
+    #[test]
+    fn rejects_non_numeric_subject() {
+        let token = sign(&Header::new(Algorithm::HS256), &claims("not-a-number"));
+        let result = generator(SECRET).parse(token);
+        assert!(matches!(result, Err(Error::Unauthorized(_))));
     }
 }
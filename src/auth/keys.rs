@@ -0,0 +1,174 @@
+use crate::domain::{Error, JsonWebKey, PublicKeyProvider};
+use aws_lc_rs::signature::{Ed25519KeyPair, KeyPair, RsaKeyPair, RsaPublicKeyComponents};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+pub struct SigningKey {
+    pub kid: Option<String>,
+    pub algorithm: Algorithm,
+    pub encoding_key: EncodingKey,
+    pub decoding_key: DecodingKey,
+    public_key: Option<JsonWebKey>,
+    retire_after: Option<OffsetDateTime>,
+}
+
+impl SigningKey {
+    fn is_retired(&self) -> bool {
+        self.retire_after
+            .is_some_and(|retire_after| retire_after <= OffsetDateTime::now_utc())
+    }
+
+    fn rsa(kid: String, pem: &pem::Pem) -> Result<Self, Error> {
+        if pem.tag() != "RSA PRIVATE KEY" {
+            return Err(Error::Unknown(format!(
+                "key {} must be a PKCS#1 RSA private key",
+                kid
+            )));
+        }
+        let key_pair = RsaKeyPair::from_der(pem.contents())
+            .map_err(|err| Error::Unknown(format!("invalid RSA key {}: {}", kid, err)))?;
+        let components = RsaPublicKeyComponents::<Vec<u8>>::from(key_pair.public_key());
+        Ok(Self {
+            algorithm: Algorithm::RS256,
+            encoding_key: EncodingKey::from_rsa_der(pem.contents()),
+            decoding_key: DecodingKey::from_rsa_raw_components(&components.n, &components.e),
+            public_key: Some(JsonWebKey::Rsa {
+                kid: kid.clone(),
+                n: URL_SAFE_NO_PAD.encode(&components.n),
+                e: URL_SAFE_NO_PAD.encode(&components.e),
+            }),
+            kid: Some(kid),
+            retire_after: None,
+        })
+    }
+
+    fn ed25519(kid: String, pem: &pem::Pem) -> Result<Self, Error> {
+        if pem.tag() != "PRIVATE KEY" {
+            return Err(Error::Unknown(format!(
+                "key {} must be a PKCS#8 Ed25519 private key",
+                kid
+            )));
+        }
+        let key_pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(pem.contents())
+            .map_err(|err| Error::Unknown(format!("invalid Ed25519 key {}: {}", kid, err)))?;
+        let x = URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref());
+        Ok(Self {
+            algorithm: Algorithm::EdDSA,
+            encoding_key: EncodingKey::from_ed_der(pem.contents()),
+            decoding_key: DecodingKey::from_ed_components(&x)
+                .map_err(|err| Error::Unknown(err.to_string()))?,
+            public_key: Some(JsonWebKey::Ed25519 {
+                kid: kid.clone(),
+                x,
+            }),
+            kid: Some(kid),
+            retire_after: None,
+        })
+    }
+}
+
+pub struct KeyRing {
+    active: usize,
+    keys: Vec<SigningKey>,
+}
+
+impl KeyRing {
+    pub fn hmac(secret: &str) -> Self {
+        Self {
+            active: 0,
+            keys: vec![SigningKey {
+                kid: None,
+                algorithm: Algorithm::HS256,
+                encoding_key: EncodingKey::from_secret(secret.as_bytes()),
+                decoding_key: DecodingKey::from_secret(secret.as_bytes()),
+                public_key: None,
+                retire_after: None,
+            }],
+        }
+    }
+
+    pub fn load(spec: &str, active_kid: &str) -> Result<Self, Error> {
+        let mut keys = Vec::new();
+        for entry in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let mut parts = entry.splitn(3, ':');
+            let (kid, algorithm, path) = match (parts.next(), parts.next(), parts.next()) {
+                (Some(kid), Some(algorithm), Some(path)) => (kid.to_string(), algorithm, path),
+                _ => {
+                    return Err(Error::Unknown(format!(
+                        "invalid key entry, expected kid:algorithm:path[@retire_after]: {}",
+                        entry
+                    )));
+                }
+            };
+            let (path, retire_after) = match path.rsplit_once('@') {
+                Some((path, retire_after)) => {
+                    let retire_after =
+                        OffsetDateTime::parse(retire_after, &Rfc3339).map_err(|err| {
+                            Error::Unknown(format!("invalid retire_after for key {}: {}", kid, err))
+                        })?;
+                    (path, Some(retire_after))
+                }
+                None => (path, None),
+            };
+            if keys
+                .iter()
+                .any(|key: &SigningKey| key.kid.as_deref() == Some(&kid))
+            {
+                return Err(Error::Unknown(format!("duplicate key id: {}", kid)));
+            }
+            let contents = std::fs::read(path)
+                .map_err(|err| Error::Unknown(format!("failed to read key {}: {}", kid, err)))?;
+            let pem = pem::parse(contents)
+                .map_err(|err| Error::Unknown(format!("invalid PEM for key {}: {}", kid, err)))?;
+            let mut key = match algorithm {
+                "RS256" => SigningKey::rsa(kid, &pem)?,
+                "EdDSA" => SigningKey::ed25519(kid, &pem)?,
+                _ => {
+                    return Err(Error::Unknown(format!(
+                        "unsupported algorithm for key {}: {}",
+                        kid, algorithm
+                    )));
+                }
+            };
+            key.retire_after = retire_after;
+            keys.push(key);
+        }
+        let active = keys
+            .iter()
+            .position(|key| key.kid.as_deref() == Some(active_kid))
+            .ok_or_else(|| Error::Unknown(format!("unknown active key id: {}", active_kid)))?;
+        if keys[active].retire_after.is_some() {
+            return Err(Error::Unknown(format!(
+                "active key {} must not have a retire_after time",
+                active_kid
+            )));
+        }
+        Ok(Self { active, keys })
+    }
+
+    pub fn signing_key(&self) -> &SigningKey {
+        &self.keys[self.active]
+    }
+
+    pub fn verification_key(&self, kid: Option<&str>) -> Option<&SigningKey> {
+        self.keys
+            .iter()
+            .find(|key| key.kid.as_deref() == kid)
+            .filter(|key| !key.is_retired())
+    }
+}
+
+impl PublicKeyProvider for KeyRing {
+    fn public_keys(&self) -> Vec<JsonWebKey> {
+        self.keys
+            .iter()
+            .filter(|key| !key.is_retired())
+            .filter_map(|key| key.public_key.clone())
+            .collect()
+    }
+}
@@ -0,0 +1,69 @@
+use crate::domain::{Error, MfaChallenge, MfaTokenSigner as MfaTokenSignerTrait, PendingMfaLogin};
+use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use serde::{Deserialize, Serialize};
+use time::Duration;
+
+const AUDIENCE: &str = "mfa";
+
+#[derive(Serialize, Deserialize, Clone)]
+struct Claims {
+    exp: usize,
+    aud: String,
+    sub: String,
+    #[serde(default)]
+    scope: String,
+}
+
+pub struct MfaTokenSigner {
+    duration: Duration,
+    secret: String,
+}
+
+impl MfaTokenSigner {
+    pub fn new(duration: Duration, secret: String) -> Self {
+        Self { duration, secret }
+    }
+}
+
+impl MfaTokenSignerTrait for MfaTokenSigner {
+    fn sign(&self, login: &PendingMfaLogin) -> Result<MfaChallenge, Error> {
+        let expires = time::OffsetDateTime::now_utc() + self.duration;
+        let claims = Claims {
+            exp: expires.unix_timestamp() as usize,
+            aud: AUDIENCE.to_string(),
+            sub: login.account_id.to_string(),
+            scope: login.scopes.join(" "),
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(self.secret.as_ref()),
+        )
+        .map_err(|err| Error::Unknown(err.to_string()))?;
+        Ok(MfaChallenge { token, expires })
+    }
+
+    fn verify(&self, token: String) -> Result<PendingMfaLogin, Error> {
+        let mut validation = Validation::default();
+        validation.set_audience(&[AUDIENCE]);
+        let data = decode::<Claims>(
+            token,
+            &DecodingKey::from_secret(self.secret.as_bytes()),
+            &validation,
+        )
+        .map_err(|err| Error::Unauthorized(err.to_string()))?;
+        Ok(PendingMfaLogin {
+            account_id: data
+                .claims
+                .sub
+                .parse()
+                .map_err(|_| Error::Unauthorized("invalid subject".to_string()))?,
+            scopes: data
+                .claims
+                .scope
+                .split_whitespace()
+                .map(str::to_string)
+                .collect(),
+        })
+    }
+}
@@ -1,4 +1,14 @@
+mod email;
 mod jwt;
+mod keys;
+mod mfa;
 mod password;
+mod secret;
+mod totp;
+pub use email::*;
 pub use jwt::*;
+pub use keys::*;
+pub use mfa::*;
 pub use password::*;
+pub use secret::*;
+pub use totp::*;
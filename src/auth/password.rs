@@ -1,31 +1,105 @@
 use crate::domain::{Error, PasswordHasher as PasswordHasherTrait};
 use argon2::{
-    Argon2, PasswordHash, PasswordVerifier,
+    Algorithm, Argon2, Params, PasswordHash, PasswordVerifier, Version,
     password_hash::{PasswordHasher as ArgonPasswordHasher, SaltString, rand_core::OsRng},
 };
 
-pub struct PasswordHasher;
+#[derive(Clone)]
+pub struct PasswordHasher {
+    params: Params,
+}
 
 impl PasswordHasher {
-    pub fn new() -> Self {
-        Self
+    pub fn new(memory_cost: u32, time_cost: u32, parallelism: u32) -> Result<Self, Error> {
+        let params = Params::new(memory_cost, time_cost, parallelism, None)
+            .map_err(|err| Error::Unknown(err.to_string()))?;
+        Ok(Self { params })
+    }
+
+    fn argon(&self) -> Argon2<'_> {
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
     }
 }
 
 impl PasswordHasherTrait for PasswordHasher {
     fn hash(&self, password: String) -> Result<String, Error> {
         let salt = SaltString::generate(&mut OsRng);
-        let argon = Argon2::default();
-        let hash = argon
+        let hash = self
+            .argon()
             .hash_password(password.as_bytes(), salt.as_salt())
-            .unwrap()
+            .map_err(|err| Error::Unknown(err.to_string()))?
             .to_string();
         Ok(hash)
     }
 
-    fn verify(&self, password: String, actual: String) -> bool {
-        let parsed = PasswordHash::new(&actual).unwrap();
-        let argon = Argon2::default();
-        argon.verify_password(password.as_bytes(), &parsed).is_ok()
+    fn verify(&self, password: String, actual: String) -> Result<bool, Error> {
+        let parsed = PasswordHash::new(&actual).map_err(|err| Error::Unknown(err.to_string()))?;
+        Ok(self
+            .argon()
+            .verify_password(password.as_bytes(), &parsed)
+            .is_ok())
+    }
+
+    fn needs_rehash(&self, actual: &str) -> bool {
+        let parsed = match PasswordHash::new(actual) {
+            Ok(parsed) => parsed,
+            Err(_) => return true,
+        };
+        if parsed.algorithm != Algorithm::Argon2id.ident() {
+            return true;
+        }
+        match Params::try_from(&parsed) {
+            Ok(params) => {
+                params.m_cost() < self.params.m_cost()
+                    || params.t_cost() < self.params.t_cost()
+                    || params.p_cost() < self.params.p_cost()
+            }
+            Err(_) => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hasher() -> PasswordHasher {
+        PasswordHasher::new(Params::MIN_M_COST, 1, 1).unwrap()
+    }
+
+    #[test]
+    fn verifies_own_hash() {
+        let hasher = hasher();
+        let hash = hasher.hash("correct horse".to_string()).unwrap();
+        assert!(
+            hasher
+                .verify("correct horse".to_string(), hash.clone())
+                .unwrap()
+        );
+        assert!(!hasher.verify("wrong horse".to_string(), hash).unwrap());
+    }
+
+    #[test]
+    fn rejects_corrupted_hash() {
+        let hasher = hasher();
+        let hash = hasher.hash("correct horse".to_string()).unwrap();
+        for corrupted in [
+            String::new(),
+            "not-a-phc-string".to_string(),
+            "$argon2id$v=19$m=abc,t=1,p=1$c2FsdA$aGFzaA".to_string(),
+            hash.replace('$', "#"),
+        ] {
+            let result = hasher.verify("correct horse".to_string(), corrupted.clone());
+            assert!(matches!(result, Err(Error::Unknown(_))), "{}", corrupted);
+        }
+    }
+
+    #[test]
+    fn truncated_hash_does_not_verify() {
+        let hasher = hasher();
+        let hash = hasher.hash("correct horse".to_string()).unwrap();
+        let truncated = &hash[..hash.len() - 4];
+        let result = hasher.verify("correct horse".to_string(), truncated.to_string());
+        assert!(!matches!(result, Ok(true)));
     }
 }
@@ -0,0 +1,41 @@
+use crate::domain::{Secret, SecretGenerator as SecretGeneratorTrait};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+const SECRET_LENGTH: usize = 32;
+const CODE_LENGTH: usize = 10;
+const CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+
+pub struct SecretGenerator;
+
+impl SecretGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl SecretGeneratorTrait for SecretGenerator {
+    fn generate(&self) -> Secret {
+        let mut bytes = [0u8; SECRET_LENGTH];
+        OsRng.fill_bytes(&mut bytes);
+        let value = hex::encode(bytes);
+        let hash = self.hash(&value);
+        Secret { value, hash }
+    }
+
+    fn generate_code(&self) -> String {
+        let mut code = String::with_capacity(CODE_LENGTH + 1);
+        for i in 0..CODE_LENGTH {
+            if i == CODE_LENGTH / 2 {
+                code.push('-');
+            }
+            let index = OsRng.next_u32() as usize % CODE_ALPHABET.len();
+            code.push(CODE_ALPHABET[index] as char);
+        }
+        code
+    }
+
+    fn hash(&self, value: &str) -> String {
+        hex::encode(Sha256::digest(value.as_bytes()))
+    }
+}
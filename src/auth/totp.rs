@@ -0,0 +1,59 @@
+use crate::domain::{Error, TotpVerifier as TotpVerifierTrait};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use std::time::{SystemTime, UNIX_EPOCH};
+use totp_rs::{Algorithm, Secret, TOTP};
+
+const SECRET_LENGTH: usize = 20;
+const DIGITS: usize = 6;
+const SKEW: u8 = 1;
+const STEP: u64 = 30;
+
+pub struct TotpVerifier {
+    issuer: String,
+}
+
+impl TotpVerifier {
+    pub fn new(issuer: String) -> Self {
+        Self { issuer }
+    }
+
+    fn totp(&self, secret: &str, account_name: &str) -> Result<TOTP, Error> {
+        let secret = Secret::Encoded(secret.to_string())
+            .to_bytes()
+            .map_err(|err| Error::Unknown(err.to_string()))?;
+        TOTP::new(
+            Algorithm::SHA1,
+            DIGITS,
+            0,
+            STEP,
+            secret,
+            Some(self.issuer.clone()),
+            account_name.to_string(),
+        )
+        .map_err(|err| Error::Unknown(err.to_string()))
+    }
+}
+
+impl TotpVerifierTrait for TotpVerifier {
+    fn generate_secret(&self) -> String {
+        let mut bytes = vec![0u8; SECRET_LENGTH];
+        OsRng.fill_bytes(&mut bytes);
+        Secret::Raw(bytes).to_encoded().to_string()
+    }
+
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String, Error> {
+        Ok(self.totp(secret, account_name)?.get_url())
+    }
+
+    fn verify(&self, secret: &str, code: &str) -> Result<Option<u64>, Error> {
+        let totp = self.totp(secret, "")?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|err| Error::Unknown(err.to_string()))?
+            .as_secs();
+        let current = now / STEP;
+        let skew = SKEW as u64;
+        Ok((current.saturating_sub(skew)..=current + skew)
+            .find(|step| totp.check(code.trim(), step * STEP)))
+    }
+}
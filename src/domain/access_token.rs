@@ -0,0 +1,56 @@
+use crate::domain::errors::Error;
+
+pub const ACCESS_TOKEN_PREFIX: &str = "pat_";
+
+#[derive(Clone)]
+pub struct AccessToken {
+    pub id: i32,
+    pub account_id: i32,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub last_used_at: Option<time::OffsetDateTime>,
+    pub expires_at: Option<time::OffsetDateTime>,
+    pub created_at: time::OffsetDateTime,
+}
+
+pub struct IssuedAccessToken {
+    pub token: AccessToken,
+    pub secret: String,
+}
+
+pub struct IssueAccessTokenRequest {
+    pub account_id: i32,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub duration: Option<time::Duration>,
+}
+
+pub struct CreateAccessTokenRequest {
+    pub account_id: i32,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub token_hash: String,
+    pub duration: Option<time::Duration>,
+}
+
+pub struct RevokeAccessTokenRequest {
+    pub account_id: i32,
+    pub id: i32,
+}
+
+#[async_trait]
+pub trait AccessTokenRepository: Send + Sync {
+    async fn create(&self, request: CreateAccessTokenRequest) -> Result<AccessToken, Error>;
+    async fn list(&self, account_id: i32) -> Result<Vec<AccessToken>, Error>;
+    async fn get_by_hash(&self, token_hash: String) -> Result<AccessToken, Error>;
+    async fn revoke(&self, request: RevokeAccessTokenRequest) -> Result<bool, Error>;
+    async fn touch(&self, id: i32) -> Result<(), Error>;
+}
+
+#[async_trait]
+pub trait AccessTokenService: Send + Sync {
+    async fn issue(&self, request: IssueAccessTokenRequest) -> Result<IssuedAccessToken, Error>;
+    async fn list(&self, account_id: i32) -> Result<Vec<AccessToken>, Error>;
+    async fn revoke(&self, request: RevokeAccessTokenRequest) -> Result<(), Error>;
+    async fn authorize(&self, token: String) -> Result<AccessToken, Error>;
+}
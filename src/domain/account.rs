@@ -1,27 +1,129 @@
-use crate::domain::errors;
+use std::{fmt, str::FromStr};
+
+use crate::domain::{
+    ClientInfo, DeleteSessionRequest, MfaChallenge, Project, RefreshRequest, Session, Tag,
+    TodoItem, TokenPair, VerifyMfaRequest, errors,
+};
+
+#[derive(PartialEq, Clone)]
+pub enum Role {
+    User,
+    Admin,
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Role::User => write!(f, "user"),
+            Role::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user" => Ok(Role::User),
+            "admin" => Ok(Role::Admin),
+            _ => Err(format!("Invalid role: {}", s)),
+        }
+    }
+}
 
 pub struct Account {
     pub id: i32,
-    #[allow(dead_code)]
     pub login: String,
     pub password: String,
-    #[allow(dead_code)]
+    pub email: Option<String>,
+    pub email_verified_at: Option<time::OffsetDateTime>,
+    pub totp_secret: Option<String>,
+    pub totp_enabled_at: Option<time::OffsetDateTime>,
+    pub role: Role,
+    pub disabled_at: Option<time::OffsetDateTime>,
     pub created_at: time::OffsetDateTime,
 }
 
+pub fn normalize_login(login: &str) -> String {
+    login.trim().to_lowercase()
+}
+
+impl Account {
+    pub fn is_disabled(&self) -> bool {
+        self.disabled_at.is_some()
+    }
+
+    pub fn verified_email(&self) -> Option<&str> {
+        match self.email_verified_at {
+            Some(_) => self.email.as_deref(),
+            None => None,
+        }
+    }
+}
+
 pub struct RegisterRequest {
     pub login: String,
     pub password: String,
+    pub email: Option<String>,
+    pub client: ClientInfo,
 }
 
 pub struct LoginRequest {
     pub login: String,
     pub password: String,
+    pub scopes: Option<Vec<String>>,
+    pub client: ClientInfo,
+}
+
+pub enum LoginResult {
+    Authenticated(TokenPair),
+    MfaRequired(MfaChallenge),
+}
+
+pub struct MfaLoginRequest {
+    pub verification: VerifyMfaRequest,
+    pub client: ClientInfo,
+}
+
+pub struct ChangePasswordRequest {
+    pub account_id: i32,
+    pub session_id: String,
+    pub current_password: String,
+    pub new_password: String,
+}
+
+pub struct DeleteAccountRequest {
+    pub account_id: i32,
+    pub password: String,
+}
+
+pub struct AccountExport {
+    pub account: Account,
+    pub todo_items: Vec<TodoItem>,
+    pub projects: Vec<Project>,
+    pub tags: Vec<Tag>,
+    pub exported_at: time::OffsetDateTime,
 }
 
 pub struct CreateAccountRequest {
     pub login: String,
     pub password: String,
+    pub email: Option<String>,
+}
+
+pub struct AccountFilters {
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+}
+
+pub struct EmailVerificationClaims {
+    pub account_id: i32,
+    pub email: String,
+}
+
+pub struct VerifyEmailRequest {
+    pub token: String,
 }
 
 pub struct AuthToken {
@@ -31,12 +133,50 @@ pub struct AuthToken {
 
 pub trait PasswordHasher: Sync + Send {
     fn hash(&self, password: String) -> Result<String, errors::Error>;
-    fn verify(&self, password: String, actual: String) -> bool;
+    fn verify(&self, password: String, actual: String) -> Result<bool, errors::Error>;
+    fn needs_rehash(&self, actual: &str) -> bool;
+}
+
+#[derive(Clone)]
+pub struct AuthTokenClaims {
+    pub account_id: i32,
+    pub token_id: String,
+    pub session_id: String,
+    pub scopes: Vec<String>,
+    pub issued_at: time::OffsetDateTime,
+    pub expires: time::OffsetDateTime,
 }
 
 pub trait AuthTokenGenerator: Sync + Send {
-    fn generate(&self, account_id: i32) -> Result<AuthToken, errors::Error>;
-    fn parse(&self, token: String) -> Result<i32, errors::Error>;
+    fn generate(
+        &self,
+        account_id: i32,
+        session_id: String,
+        scopes: Vec<String>,
+    ) -> Result<AuthToken, errors::Error>;
+    fn parse(&self, token: String) -> Result<AuthTokenClaims, errors::Error>;
+}
+
+#[derive(Clone)]
+pub enum JsonWebKey {
+    Rsa { kid: String, n: String, e: String },
+    Ed25519 { kid: String, x: String },
+}
+
+pub trait PublicKeyProvider: Sync + Send {
+    fn public_keys(&self) -> Vec<JsonWebKey>;
+}
+
+#[async_trait]
+pub trait EmailVerifier: Send + Sync {
+    async fn send(&self, account_id: i32, email: String) -> Result<(), errors::Error>;
+    fn verify(&self, token: String) -> Result<EmailVerificationClaims, errors::Error>;
+    fn is_required(&self) -> bool;
+}
+
+pub trait EmailVerificationSigner: Sync + Send {
+    fn sign(&self, claims: &EmailVerificationClaims) -> Result<String, errors::Error>;
+    fn verify(&self, token: String) -> Result<EmailVerificationClaims, errors::Error>;
 }
 
 #[async_trait]
@@ -44,11 +184,45 @@ pub trait AccountRepository: Send + Sync {
     async fn create(&self, request: CreateAccountRequest) -> Result<Account, errors::Error>;
     async fn get(&self, id: i32) -> Result<Account, errors::Error>;
     async fn get_by_login(&self, login: String) -> Result<Account, errors::Error>;
+    async fn update_password(&self, id: i32, password: String) -> Result<(), errors::Error>;
+    async fn verify_email(&self, id: i32, email: String) -> Result<bool, errors::Error>;
+    async fn set_totp_secret(&self, id: i32, secret: String) -> Result<(), errors::Error>;
+    async fn enable_totp(&self, id: i32) -> Result<(), errors::Error>;
+    async fn accept_totp_step(&self, id: i32, step: u64) -> Result<bool, errors::Error>;
+    async fn list(&self, filters: &AccountFilters) -> Result<Vec<Account>, errors::Error>;
+    async fn count(&self) -> Result<i64, errors::Error>;
+    async fn set_disabled(&self, id: i32, disabled: bool) -> Result<bool, errors::Error>;
+    async fn delete(&self, id: i32) -> Result<bool, errors::Error>;
 }
 
 #[async_trait]
 pub trait AccountService: Send + Sync {
-    async fn register(&self, request: RegisterRequest) -> Result<AuthToken, errors::Error>;
-    async fn login(&self, request: LoginRequest) -> Result<AuthToken, errors::Error>;
-    async fn authorize(&self, token: String) -> Result<i32, errors::Error>;
+    async fn register(&self, request: RegisterRequest) -> Result<TokenPair, errors::Error>;
+    async fn login(&self, request: LoginRequest) -> Result<LoginResult, errors::Error>;
+    async fn login_mfa(&self, request: MfaLoginRequest) -> Result<TokenPair, errors::Error>;
+    async fn refresh(&self, request: RefreshRequest) -> Result<TokenPair, errors::Error>;
+    async fn authorize(&self, token: String) -> Result<AuthTokenClaims, errors::Error>;
+    async fn logout(&self, claims: AuthTokenClaims) -> Result<(), errors::Error>;
+    async fn logout_all(&self, account_id: i32) -> Result<(), errors::Error>;
+    async fn change_password(&self, request: ChangePasswordRequest) -> Result<(), errors::Error>;
+    async fn verify_email(&self, request: VerifyEmailRequest) -> Result<(), errors::Error>;
+    async fn resend_verification(&self, account_id: i32) -> Result<(), errors::Error>;
+    async fn require_verified(&self, account_id: i32) -> Result<(), errors::Error>;
+    async fn sessions(&self, account_id: i32) -> Result<Vec<Session>, errors::Error>;
+    async fn delete_session(&self, request: DeleteSessionRequest) -> Result<(), errors::Error>;
+}
+
+#[async_trait]
+pub trait AdminService: Send + Sync {
+    async fn require_admin(&self, account_id: i32) -> Result<(), errors::Error>;
+    async fn accounts(&self, filters: AccountFilters)
+    -> Result<(Vec<Account>, i64), errors::Error>;
+    async fn disable(&self, admin_id: i32, account_id: i32) -> Result<(), errors::Error>;
+    async fn enable(&self, account_id: i32) -> Result<(), errors::Error>;
+}
+
+#[async_trait]
+pub trait PersonalDataService: Send + Sync {
+    async fn export(&self, account_id: i32) -> Result<AccountExport, errors::Error>;
+    async fn delete(&self, request: DeleteAccountRequest) -> Result<(), errors::Error>;
 }
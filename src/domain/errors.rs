@@ -1,7 +1,26 @@
 #[derive(Debug)]
 pub enum Error {
     NotFound(String),
+    Unauthorized(String),
     Forbidden(String),
     OperationNotApplicable(String),
+    Conflict(String),
+    Validation(Vec<FieldError>),
+    RateLimited(time::Duration),
     Unknown(String),
 }
+
+#[derive(Debug)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl Error {
+    pub fn validation(field: &str, message: impl Into<String>) -> Self {
+        Self::Validation(vec![FieldError {
+            field: field.to_string(),
+            message: message.into(),
+        }])
+    }
+}
@@ -0,0 +1,67 @@
+use std::{fmt, sync::Arc};
+
+use crate::domain::errors::Error;
+
+pub enum LoginAttemptOutcome {
+    Success,
+    Failure,
+    Locked,
+}
+
+impl fmt::Display for LoginAttemptOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoginAttemptOutcome::Success => write!(f, "success"),
+            LoginAttemptOutcome::Failure => write!(f, "failure"),
+            LoginAttemptOutcome::Locked => write!(f, "locked"),
+        }
+    }
+}
+
+pub struct RecordLoginAttemptRequest {
+    pub login: String,
+    pub ip_address: Option<String>,
+    pub outcome: LoginAttemptOutcome,
+}
+
+pub struct LoginFailures {
+    pub count: i64,
+    pub last_failed_at: Option<time::OffsetDateTime>,
+}
+
+#[async_trait]
+pub trait LoginAttemptRepository: Send + Sync {
+    async fn record(&self, request: RecordLoginAttemptRequest) -> Result<(), Error>;
+    async fn failures_by_login(
+        &self,
+        login: String,
+        window: time::Duration,
+    ) -> Result<LoginFailures, Error>;
+    async fn failures_by_ip(
+        &self,
+        ip_address: String,
+        window: time::Duration,
+    ) -> Result<LoginFailures, Error>;
+}
+
+#[async_trait]
+pub trait LoginThrottle: Send + Sync {
+    async fn check(&self, login: String, ip_address: Option<String>) -> Result<(), Error>;
+    async fn record_failure(&self, login: String, ip_address: Option<String>) -> Result<(), Error>;
+    async fn record_success(&self, login: String, ip_address: Option<String>) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl<T: LoginThrottle + ?Sized> LoginThrottle for Arc<T> {
+    async fn check(&self, login: String, ip_address: Option<String>) -> Result<(), Error> {
+        (**self).check(login, ip_address).await
+    }
+
+    async fn record_failure(&self, login: String, ip_address: Option<String>) -> Result<(), Error> {
+        (**self).record_failure(login, ip_address).await
+    }
+
+    async fn record_success(&self, login: String, ip_address: Option<String>) -> Result<(), Error> {
+        (**self).record_success(login, ip_address).await
+    }
+}
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::domain::errors::Error;
+
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, mail: Mail) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl<T: Mailer + ?Sized> Mailer for Arc<T> {
+    async fn send(&self, mail: Mail) -> Result<(), Error> {
+        (**self).send(mail).await
+    }
+}
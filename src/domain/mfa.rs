@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use crate::domain::{Account, errors::Error};
+
+pub struct RecoveryCode {
+    pub id: i32,
+    pub code_hash: String,
+}
+
+pub struct TotpEnrollment {
+    pub secret: String,
+    pub uri: String,
+}
+
+pub struct MfaChallenge {
+    pub token: String,
+    pub expires: time::OffsetDateTime,
+}
+
+pub struct PendingMfaLogin {
+    pub account_id: i32,
+    pub scopes: Vec<String>,
+}
+
+pub struct ConfirmTotpRequest {
+    pub account_id: i32,
+    pub code: String,
+}
+
+pub struct VerifyMfaRequest {
+    pub mfa_token: String,
+    pub code: Option<String>,
+    pub recovery_code: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+pub trait TotpVerifier: Sync + Send {
+    fn generate_secret(&self) -> String;
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String, Error>;
+    fn verify(&self, secret: &str, code: &str) -> Result<Option<u64>, Error>;
+}
+
+pub trait MfaTokenSigner: Sync + Send {
+    fn sign(&self, login: &PendingMfaLogin) -> Result<MfaChallenge, Error>;
+    fn verify(&self, token: String) -> Result<PendingMfaLogin, Error>;
+}
+
+#[async_trait]
+pub trait RecoveryCodeRepository: Send + Sync {
+    async fn replace(&self, account_id: i32, code_hashes: Vec<String>) -> Result<(), Error>;
+    async fn list_unused(&self, account_id: i32) -> Result<Vec<RecoveryCode>, Error>;
+    async fn mark_used(&self, id: i32) -> Result<bool, Error>;
+}
+
+#[async_trait]
+pub trait MfaService: Send + Sync {
+    async fn enroll(&self, account_id: i32) -> Result<TotpEnrollment, Error>;
+    async fn confirm(&self, request: ConfirmTotpRequest) -> Result<Vec<String>, Error>;
+    async fn challenge(
+        &self,
+        account: &Account,
+        scopes: &[String],
+    ) -> Result<Option<MfaChallenge>, Error>;
+    async fn verify(&self, request: VerifyMfaRequest) -> Result<PendingMfaLogin, Error>;
+}
+
+#[async_trait]
+impl<T: MfaService + ?Sized> MfaService for Arc<T> {
+    async fn enroll(&self, account_id: i32) -> Result<TotpEnrollment, Error> {
+        (**self).enroll(account_id).await
+    }
+
+    async fn confirm(&self, request: ConfirmTotpRequest) -> Result<Vec<String>, Error> {
+        (**self).confirm(request).await
+    }
+
+    async fn challenge(
+        &self,
+        account: &Account,
+        scopes: &[String],
+    ) -> Result<Option<MfaChallenge>, Error> {
+        (**self).challenge(account, scopes).await
+    }
+
+    async fn verify(&self, request: VerifyMfaRequest) -> Result<PendingMfaLogin, Error> {
+        (**self).verify(request).await
+    }
+}
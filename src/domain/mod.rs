@@ -1,6 +1,26 @@
+mod access_token;
 mod account;
 mod errors;
+mod login_attempt;
+mod mail;
+mod mfa;
+mod password_reset;
+mod project;
+mod rate_limit;
+mod session;
+mod tag;
 mod todo;
+mod token;
+pub use access_token::*;
 pub use account::*;
 pub use errors::*;
+pub use login_attempt::*;
+pub use mail::*;
+pub use mfa::*;
+pub use password_reset::*;
+pub use project::*;
+pub use rate_limit::*;
+pub use session::*;
+pub use tag::*;
 pub use todo::*;
+pub use token::*;
@@ -0,0 +1,39 @@
+use crate::domain::errors::Error;
+
+pub struct PasswordResetToken {
+    pub id: i32,
+    pub account_id: i32,
+    pub expires_at: time::OffsetDateTime,
+    pub used_at: Option<time::OffsetDateTime>,
+}
+
+pub struct CreatePasswordResetTokenRequest {
+    pub account_id: i32,
+    pub token_hash: String,
+    pub duration: time::Duration,
+}
+
+pub struct ForgotPasswordRequest {
+    pub login: String,
+}
+
+pub struct ResetPasswordRequest {
+    pub token: String,
+    pub new_password: String,
+}
+
+#[async_trait]
+pub trait PasswordResetTokenRepository: Send + Sync {
+    async fn create(
+        &self,
+        request: CreatePasswordResetTokenRequest,
+    ) -> Result<PasswordResetToken, Error>;
+    async fn get_by_hash(&self, token_hash: String) -> Result<PasswordResetToken, Error>;
+    async fn mark_used(&self, id: i32) -> Result<bool, Error>;
+}
+
+#[async_trait]
+pub trait PasswordResetService: Send + Sync {
+    async fn forgot(&self, request: ForgotPasswordRequest) -> Result<(), Error>;
+    async fn reset(&self, request: ResetPasswordRequest) -> Result<(), Error>;
+}
@@ -0,0 +1,72 @@
+use crate::domain::errors::Error;
+
+pub struct Project {
+    pub id: i32,
+    pub name: String,
+    pub archived_at: Option<time::OffsetDateTime>,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+}
+
+impl Project {
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
+    }
+}
+
+pub enum ProjectFilter {
+    Unassigned,
+    Project(i32),
+}
+
+pub struct ProjectFilters {
+    pub owner_id: i32,
+    pub archived: bool,
+}
+
+pub struct CreateProjectRequest {
+    pub owner_id: i32,
+    pub name: String,
+}
+
+pub struct RenameProjectRequest {
+    pub owner_id: i32,
+    pub project_id: i32,
+    pub name: String,
+}
+
+pub struct ArchiveProjectRequest {
+    pub owner_id: i32,
+    pub project_id: i32,
+    pub archived: bool,
+}
+
+pub enum DeleteProjectMode {
+    Move { target_id: Option<i32> },
+    Cascade,
+}
+
+pub struct DeleteProjectRequest {
+    pub owner_id: i32,
+    pub project_id: i32,
+    pub mode: DeleteProjectMode,
+}
+
+#[async_trait]
+pub trait ProjectRepository: Send + Sync {
+    async fn create(&self, request: CreateProjectRequest) -> Result<Project, Error>;
+    async fn list(&self, filters: &ProjectFilters) -> Result<Vec<Project>, Error>;
+    async fn get(&self, owner_id: i32, project_id: i32) -> Result<Project, Error>;
+    async fn rename(&self, request: RenameProjectRequest) -> Result<Project, Error>;
+    async fn set_archived(&self, request: ArchiveProjectRequest) -> Result<Project, Error>;
+    async fn delete(&self, request: DeleteProjectRequest) -> Result<bool, Error>;
+}
+
+#[async_trait]
+pub trait ProjectService: Send + Sync {
+    async fn create(&self, request: CreateProjectRequest) -> Result<Project, Error>;
+    async fn list(&self, filters: ProjectFilters) -> Result<Vec<Project>, Error>;
+    async fn rename(&self, request: RenameProjectRequest) -> Result<Project, Error>;
+    async fn archive(&self, request: ArchiveProjectRequest) -> Result<Project, Error>;
+    async fn delete(&self, request: DeleteProjectRequest) -> Result<(), Error>;
+}
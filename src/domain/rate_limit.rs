@@ -0,0 +1,67 @@
+use crate::domain::errors::Error;
+
+#[derive(Clone)]
+pub struct RateLimit {
+    pub capacity: u32,
+    pub period: time::Duration,
+}
+
+impl RateLimit {
+    fn refill_per_second(&self) -> f64 {
+        self.capacity as f64 / self.period.as_seconds_f64()
+    }
+}
+
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset: time::Duration,
+    pub retry_after: time::Duration,
+}
+
+pub struct TokenBucket {
+    pub tokens: f64,
+    pub updated_at: time::OffsetDateTime,
+}
+
+impl TokenBucket {
+    pub fn full(limit: &RateLimit, now: time::OffsetDateTime) -> Self {
+        Self {
+            tokens: limit.capacity as f64,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_full(&self, limit: &RateLimit, now: time::OffsetDateTime) -> bool {
+        self.refilled(limit, now) >= limit.capacity as f64
+    }
+
+    fn refilled(&self, limit: &RateLimit, now: time::OffsetDateTime) -> f64 {
+        let elapsed = (now - self.updated_at).as_seconds_f64().max(0.0);
+        (self.tokens + elapsed * limit.refill_per_second()).min(limit.capacity as f64)
+    }
+
+    pub fn take(&mut self, limit: &RateLimit, now: time::OffsetDateTime) -> RateLimitDecision {
+        let rate = limit.refill_per_second();
+        self.tokens = self.refilled(limit, now);
+        self.updated_at = now;
+
+        let allowed = self.tokens >= 1.0;
+        if allowed {
+            self.tokens -= 1.0;
+        }
+        RateLimitDecision {
+            allowed,
+            limit: limit.capacity,
+            remaining: self.tokens.floor() as u32,
+            reset: time::Duration::seconds_f64((limit.capacity as f64 - self.tokens) / rate),
+            retry_after: time::Duration::seconds_f64(((1.0 - self.tokens) / rate).max(0.0)),
+        }
+    }
+}
+
+#[async_trait]
+pub trait RateLimitStore: Send + Sync {
+    async fn take(&self, key: String, limit: &RateLimit) -> Result<RateLimitDecision, Error>;
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::domain::{AuthTokenClaims, RefreshRequest, TokenPair, errors::Error};
+
+pub struct ClientInfo {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+pub struct Session {
+    pub id: String,
+    pub account_id: i32,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub scopes: Vec<String>,
+    pub created_at: time::OffsetDateTime,
+    pub last_seen_at: time::OffsetDateTime,
+}
+
+pub struct CreateSessionRequest {
+    pub account_id: i32,
+    pub client: ClientInfo,
+    pub scopes: Vec<String>,
+}
+
+pub struct DeleteSessionRequest {
+    pub account_id: i32,
+    pub session_id: String,
+}
+
+#[async_trait]
+pub trait SessionRepository: Send + Sync {
+    async fn create(&self, request: CreateSessionRequest) -> Result<Session, Error>;
+    async fn get(&self, id: String) -> Result<Session, Error>;
+    async fn list(&self, account_id: i32) -> Result<Vec<Session>, Error>;
+    async fn touch(&self, ids: Vec<String>) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl<T: SessionRepository + ?Sized> SessionRepository for Arc<T> {
+    async fn create(&self, request: CreateSessionRequest) -> Result<Session, Error> {
+        (**self).create(request).await
+    }
+
+    async fn get(&self, id: String) -> Result<Session, Error> {
+        (**self).get(id).await
+    }
+
+    async fn list(&self, account_id: i32) -> Result<Vec<Session>, Error> {
+        (**self).list(account_id).await
+    }
+
+    async fn touch(&self, ids: Vec<String>) -> Result<(), Error> {
+        (**self).touch(ids).await
+    }
+}
+
+#[async_trait]
+pub trait SessionService: Send + Sync {
+    async fn start(&self, request: CreateSessionRequest) -> Result<TokenPair, Error>;
+    async fn refresh(&self, request: RefreshRequest) -> Result<TokenPair, Error>;
+    async fn authorize(&self, token: String) -> Result<AuthTokenClaims, Error>;
+    async fn logout(&self, claims: AuthTokenClaims) -> Result<(), Error>;
+    async fn logout_all(&self, account_id: i32) -> Result<(), Error>;
+    async fn logout_others(&self, account_id: i32, session_id: String) -> Result<(), Error>;
+    async fn list(&self, account_id: i32) -> Result<Vec<Session>, Error>;
+    async fn delete(&self, request: DeleteSessionRequest) -> Result<(), Error>;
+}
+
+#[async_trait]
+impl<T: SessionService + ?Sized> SessionService for Arc<T> {
+    async fn start(&self, request: CreateSessionRequest) -> Result<TokenPair, Error> {
+        (**self).start(request).await
+    }
+
+    async fn refresh(&self, request: RefreshRequest) -> Result<TokenPair, Error> {
+        (**self).refresh(request).await
+    }
+
+    async fn authorize(&self, token: String) -> Result<AuthTokenClaims, Error> {
+        (**self).authorize(token).await
+    }
+
+    async fn logout(&self, claims: AuthTokenClaims) -> Result<(), Error> {
+        (**self).logout(claims).await
+    }
+
+    async fn logout_all(&self, account_id: i32) -> Result<(), Error> {
+        (**self).logout_all(account_id).await
+    }
+
+    async fn logout_others(&self, account_id: i32, session_id: String) -> Result<(), Error> {
+        (**self).logout_others(account_id, session_id).await
+    }
+
+    async fn list(&self, account_id: i32) -> Result<Vec<Session>, Error> {
+        (**self).list(account_id).await
+    }
+
+    async fn delete(&self, request: DeleteSessionRequest) -> Result<(), Error> {
+        (**self).delete(request).await
+    }
+}
@@ -0,0 +1,67 @@
+use crate::domain::{TodoItem, errors::Error};
+
+pub struct Tag {
+    pub id: i32,
+    pub name: String,
+    pub color: Option<String>,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+}
+
+#[derive(PartialEq, Clone, Copy, Default)]
+pub enum TagMatch {
+    #[default]
+    Any,
+    All,
+}
+
+pub struct CreateTagRequest {
+    pub owner_id: i32,
+    pub name: String,
+    pub color: Option<String>,
+}
+
+pub struct UpdateTagRequest {
+    pub owner_id: i32,
+    pub tag_id: i32,
+    pub name: Option<String>,
+    pub color: Option<Option<String>>,
+}
+
+impl UpdateTagRequest {
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.color.is_none()
+    }
+}
+
+pub struct DeleteTagRequest {
+    pub owner_id: i32,
+    pub tag_id: i32,
+}
+
+pub struct TagItemRequest {
+    pub owner_id: i32,
+    pub item_id: i32,
+    pub tag_id: i32,
+}
+
+#[async_trait]
+pub trait TagRepository: Send + Sync {
+    async fn create(&self, request: CreateTagRequest) -> Result<Tag, Error>;
+    async fn list(&self, owner_id: i32) -> Result<Vec<Tag>, Error>;
+    async fn get(&self, owner_id: i32, tag_id: i32) -> Result<Tag, Error>;
+    async fn update(&self, request: UpdateTagRequest) -> Result<Tag, Error>;
+    async fn delete(&self, request: DeleteTagRequest) -> Result<bool, Error>;
+    async fn attach(&self, item_id: i32, tag_id: i32) -> Result<(), Error>;
+    async fn detach(&self, item_id: i32, tag_id: i32) -> Result<bool, Error>;
+}
+
+#[async_trait]
+pub trait TagService: Send + Sync {
+    async fn create(&self, request: CreateTagRequest) -> Result<Tag, Error>;
+    async fn list(&self, owner_id: i32) -> Result<Vec<Tag>, Error>;
+    async fn update(&self, request: UpdateTagRequest) -> Result<Tag, Error>;
+    async fn delete(&self, request: DeleteTagRequest) -> Result<(), Error>;
+    async fn attach(&self, request: TagItemRequest) -> Result<TodoItem, Error>;
+    async fn detach(&self, request: TagItemRequest) -> Result<TodoItem, Error>;
+}
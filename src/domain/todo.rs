@@ -1,5 +1,5 @@
-use crate::domain::errors::Error;
-use std::str::FromStr;
+use crate::domain::{ProjectFilter, Tag, TagMatch, errors::Error};
+use std::{fmt, str::FromStr};
 
 #[derive(PartialEq, Clone)]
 pub enum Status {
@@ -19,13 +19,13 @@ impl Status {
     }
 }
 
-impl ToString for Status {
-    fn to_string(&self) -> String {
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Status::Draft => "draft".to_string(),
-            Status::InProgress => "in_progress".to_string(),
-            Status::Completed => "completed".to_string(),
-            Status::Rejected => "rejected".to_string(),
+            Status::Draft => write!(f, "draft"),
+            Status::InProgress => write!(f, "in_progress"),
+            Status::Completed => write!(f, "completed"),
+            Status::Rejected => write!(f, "rejected"),
         }
     }
 }
@@ -44,32 +44,187 @@ impl FromStr for Status {
     }
 }
 
+#[derive(PartialEq, Clone, Copy, Default)]
+pub enum Priority {
+    #[default]
+    None,
+    Low,
+    Medium,
+    High,
+    Urgent,
+}
+
+impl Priority {
+    pub fn rank(&self) -> i16 {
+        match self {
+            Priority::None => 0,
+            Priority::Low => 1,
+            Priority::Medium => 2,
+            Priority::High => 3,
+            Priority::Urgent => 4,
+        }
+    }
+
+    pub fn from_rank(rank: i16) -> Result<Self, String> {
+        match rank {
+            0 => Ok(Priority::None),
+            1 => Ok(Priority::Low),
+            2 => Ok(Priority::Medium),
+            3 => Ok(Priority::High),
+            4 => Ok(Priority::Urgent),
+            _ => Err(format!("Invalid priority rank: {}", rank)),
+        }
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Priority::None => write!(f, "none"),
+            Priority::Low => write!(f, "low"),
+            Priority::Medium => write!(f, "medium"),
+            Priority::High => write!(f, "high"),
+            Priority::Urgent => write!(f, "urgent"),
+        }
+    }
+}
+
+impl FromStr for Priority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Priority::None),
+            "low" => Ok(Priority::Low),
+            "medium" => Ok(Priority::Medium),
+            "high" => Ok(Priority::High),
+            "urgent" => Ok(Priority::Urgent),
+            _ => Err(format!("Invalid priority: {}", s)),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum SortField {
+    Priority,
+    DueAt,
+    CreatedAt,
+    UpdatedAt,
+    Title,
+}
+
+impl FromStr for SortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "priority" => Ok(SortField::Priority),
+            "due_at" => Ok(SortField::DueAt),
+            "created_at" => Ok(SortField::CreatedAt),
+            "updated_at" => Ok(SortField::UpdatedAt),
+            "title" => Ok(SortField::Title),
+            _ => Err(format!("Invalid sort field: {}", s)),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl FromStr for SortDirection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "asc" => Ok(SortDirection::Asc),
+            "desc" => Ok(SortDirection::Desc),
+            _ => Err(format!("Invalid sort direction: {}", s)),
+        }
+    }
+}
+
+pub struct Sort {
+    pub field: SortField,
+    pub direction: SortDirection,
+}
+
 pub struct TodoItem {
     pub id: i32,
-    pub owner_id: i32,
+    pub project_id: Option<i32>,
     pub title: String,
     pub status: Status,
+    pub priority: Priority,
     pub description: String,
     pub created_at: time::OffsetDateTime,
     pub updated_at: time::OffsetDateTime,
+    pub deleted_at: Option<time::OffsetDateTime>,
+    pub due_at: Option<time::OffsetDateTime>,
+    pub tags: Vec<Tag>,
 }
 
 pub struct Filters {
+    pub owner_id: i32,
     pub status: Option<Status>,
+    pub trashed: bool,
+    pub project: Option<ProjectFilter>,
+    pub due_before: Option<time::OffsetDateTime>,
+    pub due_after: Option<time::OffsetDateTime>,
+    pub overdue: bool,
+    pub tags: Vec<String>,
+    pub tag_match: TagMatch,
+    pub sort: Vec<Sort>,
     pub limit: Option<i32>,
     pub offset: Option<i32>,
 }
 
 pub struct CreateTodoItemRequest {
     pub owner_id: i32,
+    pub project_id: Option<i32>,
     pub title: String,
     pub description: String,
+    pub priority: Priority,
+    pub due_at: Option<time::OffsetDateTime>,
+}
+
+pub struct GetTodoItemRequest {
+    pub owner_id: i32,
+    pub item_id: i32,
+    pub include_trashed: bool,
 }
 
 pub struct UpdateTodoItemRequest {
     pub owner_id: i32,
     pub item_id: i32,
-    pub status: Status,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<Status>,
+    pub priority: Option<Priority>,
+    pub due_at: Option<Option<time::OffsetDateTime>>,
+    pub project_id: Option<Option<i32>>,
+}
+
+impl UpdateTodoItemRequest {
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.description.is_none()
+            && self.status.is_none()
+            && self.priority.is_none()
+            && self.due_at.is_none()
+            && self.project_id.is_none()
+    }
+}
+
+pub struct DeleteTodoItemRequest {
+    pub owner_id: i32,
+    pub item_id: i32,
+}
+
+pub struct RestoreTodoItemRequest {
+    pub owner_id: i32,
+    pub item_id: i32,
 }
 
 #[async_trait]
@@ -94,10 +249,21 @@ pub trait TodoListerAndCounter: Send + Sync {
 
 #[async_trait]
 pub trait TodoGetter: Send + Sync {
-    async fn one(&self, id: i32) -> Result<TodoItem, Error>;
+    async fn one(&self, request: GetTodoItemRequest) -> Result<TodoItem, Error>;
 }
 
 #[async_trait]
 pub trait TodoUpdater: Send + Sync {
     async fn update(&self, request: UpdateTodoItemRequest) -> Result<TodoItem, Error>;
 }
+
+#[async_trait]
+pub trait TodoDeleter: Send + Sync {
+    async fn delete(&self, request: DeleteTodoItemRequest) -> Result<TodoItem, Error>;
+    async fn restore(&self, request: RestoreTodoItemRequest) -> Result<TodoItem, Error>;
+}
+
+#[async_trait]
+pub trait TodoPurger: Send + Sync {
+    async fn purge(&self, retention: time::Duration) -> Result<u64, Error>;
+}
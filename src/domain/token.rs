@@ -0,0 +1,88 @@
+use crate::domain::{AuthToken, AuthTokenClaims, errors::Error};
+
+pub const SCOPE_TODO_READ: &str = "todo:read";
+pub const SCOPE_TODO_WRITE: &str = "todo:write";
+pub const SCOPES: &[&str] = &[SCOPE_TODO_READ, SCOPE_TODO_WRITE];
+
+pub fn validate_scopes(scopes: &[String]) -> Result<(), Error> {
+    if scopes.is_empty() {
+        return Err(Error::validation(
+            "scopes",
+            "at least one scope is required",
+        ));
+    }
+    if let Some(scope) = scopes
+        .iter()
+        .find(|scope| !SCOPES.contains(&scope.as_str()))
+    {
+        return Err(Error::validation(
+            "scopes",
+            format!("unknown scope: {}", scope),
+        ));
+    }
+    Ok(())
+}
+
+pub struct Secret {
+    pub value: String,
+    pub hash: String,
+}
+
+pub struct RefreshToken {
+    pub id: i32,
+    pub account_id: i32,
+    pub family_id: String,
+    pub expires_at: time::OffsetDateTime,
+    pub used_at: Option<time::OffsetDateTime>,
+    pub revoked_at: Option<time::OffsetDateTime>,
+}
+
+pub struct CreateRefreshTokenRequest {
+    pub account_id: i32,
+    pub family_id: String,
+    pub token_hash: String,
+    pub duration: time::Duration,
+}
+
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
+pub struct TokenPair {
+    pub access_token: AuthToken,
+    pub refresh_token: String,
+    pub refresh_expires: time::OffsetDateTime,
+}
+
+pub struct RevokeTokenRequest {
+    pub account_id: i32,
+    pub session_id: String,
+    pub token_id: String,
+    pub expires: time::OffsetDateTime,
+}
+
+pub trait SecretGenerator: Sync + Send {
+    fn generate(&self) -> Secret;
+    fn generate_code(&self) -> String;
+    fn hash(&self, value: &str) -> String;
+}
+
+#[async_trait]
+pub trait RefreshTokenRepository: Send + Sync {
+    async fn create(&self, request: CreateRefreshTokenRequest) -> Result<RefreshToken, Error>;
+    async fn get_by_hash(&self, token_hash: String) -> Result<RefreshToken, Error>;
+    async fn mark_used(&self, id: i32) -> Result<bool, Error>;
+    async fn revoke_family(&self, family_id: String) -> Result<(), Error>;
+    async fn revoke_account(&self, account_id: i32) -> Result<(), Error>;
+    async fn revoke_other_families(&self, account_id: i32, family_id: String) -> Result<(), Error>;
+}
+
+#[async_trait]
+pub trait TokenRevocationStore: Send + Sync {
+    async fn revoke_token(&self, request: RevokeTokenRequest) -> Result<(), Error>;
+    async fn revoke_session(&self, session_id: String) -> Result<(), Error>;
+    async fn revoke_account(&self, account_id: i32) -> Result<(), Error>;
+    async fn revoke_other_sessions(&self, account_id: i32, session_id: String)
+    -> Result<(), Error>;
+    async fn is_revoked(&self, claims: &AuthTokenClaims) -> Result<bool, Error>;
+}
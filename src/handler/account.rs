@@ -2,15 +2,27 @@ use std::sync::Arc;
 
 use rocket::{State, http::Status, response::status::Custom, serde::json::Json};
 
-use crate::{domain::AccountService, handler::models};
+use crate::{
+    domain::{
+        AccessTokenService, AccountService, DeleteSessionRequest, MfaService, PasswordResetService,
+        PersonalDataService, RevokeAccessTokenRequest,
+    },
+    handler::{
+        guards::{ClientGuard, SessionGuard},
+        models,
+    },
+};
 
 #[post("/register", data = "<request>")]
 pub async fn register(
+    client: ClientGuard,
     request: Json<models::RegisterRequest>,
     service: &State<Arc<dyn AccountService>>,
-) -> Custom<Result<Json<models::Response<models::AuthTokenResponse>>, Json<models::ErrorResponse>>>
-{
-    let result = service.inner().register(request.into_inner().into()).await;
+) -> Custom<Result<Json<models::Response<models::AuthTokenResponse>>, models::ErrorResponse>> {
+    let result = service
+        .inner()
+        .register(request.into_inner().into_domain(client.into()))
+        .await;
     match result {
         Ok(token) => Custom(
             Status::Ok,
@@ -18,20 +30,58 @@ pub async fn register(
                 models::AuthTokenResponse::from(token),
             ))),
         ),
-        Err(err) => Custom(
-            Status::from(&err),
-            Err(Json(models::ErrorResponse::from(&err))),
-        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
     }
 }
 
 #[post("/login", data = "<request>")]
 pub async fn login(
+    client: ClientGuard,
     request: Json<models::LoginRequest>,
     service: &State<Arc<dyn AccountService>>,
-) -> Custom<Result<Json<models::Response<models::AuthTokenResponse>>, Json<models::ErrorResponse>>>
-{
-    let result = service.inner().login(request.into_inner().into()).await;
+) -> Custom<Result<Json<models::Response<models::LoginResponse>>, models::ErrorResponse>> {
+    let result = service
+        .inner()
+        .login(request.into_inner().into_domain(client.into()))
+        .await;
+    match result {
+        Ok(result) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(models::LoginResponse::from(
+                result,
+            )))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/login/2fa", data = "<request>")]
+pub async fn login_mfa(
+    client: ClientGuard,
+    request: Json<models::MfaLoginRequest>,
+    service: &State<Arc<dyn AccountService>>,
+) -> Custom<Result<Json<models::Response<models::AuthTokenResponse>>, models::ErrorResponse>> {
+    let result = service
+        .inner()
+        .login_mfa(request.into_inner().into_domain(client.into()))
+        .await;
+    match result {
+        Ok(token) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(
+                models::AuthTokenResponse::from(token),
+            ))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/token/refresh", data = "<request>")]
+pub async fn refresh_token(
+    request: Json<models::RefreshRequest>,
+    service: &State<Arc<dyn AccountService>>,
+) -> Custom<Result<Json<models::Response<models::AuthTokenResponse>>, models::ErrorResponse>> {
+    let result = service.inner().refresh(request.into_inner().into()).await;
     match result {
         Ok(token) => Custom(
             Status::Ok,
@@ -39,9 +89,254 @@ pub async fn login(
                 models::AuthTokenResponse::from(token),
             ))),
         ),
-        Err(err) => Custom(
-            Status::from(&err),
-            Err(Json(models::ErrorResponse::from(&err))),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/logout")]
+pub async fn logout(
+    auth_guard: SessionGuard,
+    service: &State<Arc<dyn AccountService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    match service.inner().logout(auth_guard.claims).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/logout/all")]
+pub async fn logout_all(
+    auth_guard: SessionGuard,
+    service: &State<Arc<dyn AccountService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    match service.inner().logout_all(auth_guard.account_id).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/account/password", data = "<request>")]
+pub async fn change_password(
+    auth_guard: SessionGuard,
+    request: Json<models::ChangePasswordRequest>,
+    service: &State<Arc<dyn AccountService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    let request = request.into_inner().into_domain(&auth_guard.claims);
+    match service.inner().change_password(request).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[get("/account/export")]
+pub async fn export_account(
+    auth_guard: SessionGuard,
+    service: &State<Arc<dyn PersonalDataService>>,
+) -> Custom<
+    Result<
+        models::Attachment<Json<models::Response<models::AccountExportData>>>,
+        models::ErrorResponse,
+    >,
+> {
+    match service.inner().export(auth_guard.account_id).await {
+        Ok(export) => Custom(
+            Status::Ok,
+            Ok(models::Attachment::new(
+                Json(models::Response::from(models::AccountExportData::from(
+                    export,
+                ))),
+                &format!("account-{}.json", auth_guard.account_id),
+            )),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[delete("/account", data = "<request>")]
+pub async fn delete_account(
+    auth_guard: SessionGuard,
+    request: Json<models::DeleteAccountRequest>,
+    service: &State<Arc<dyn PersonalDataService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    let request = request.into_inner().into_domain(auth_guard.account_id);
+    match service.inner().delete(request).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/account/email/verify", data = "<request>")]
+pub async fn verify_email(
+    request: Json<models::VerifyEmailRequest>,
+    service: &State<Arc<dyn AccountService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    match service
+        .inner()
+        .verify_email(request.into_inner().into())
+        .await
+    {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/account/email/resend")]
+pub async fn resend_verification_email(
+    auth_guard: SessionGuard,
+    service: &State<Arc<dyn AccountService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    match service
+        .inner()
+        .resend_verification(auth_guard.account_id)
+        .await
+    {
+        Ok(()) => Custom(Status::Accepted, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/password/forgot", data = "<request>")]
+pub async fn forgot_password(
+    request: Json<models::ForgotPasswordRequest>,
+    service: &State<Arc<dyn PasswordResetService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    match service.inner().forgot(request.into_inner().into()).await {
+        Ok(()) => Custom(Status::Accepted, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/password/reset", data = "<request>")]
+pub async fn reset_password(
+    request: Json<models::ResetPasswordRequest>,
+    service: &State<Arc<dyn PasswordResetService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    match service.inner().reset(request.into_inner().into()).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[get("/sessions")]
+pub async fn get_sessions(
+    auth_guard: SessionGuard,
+    service: &State<Arc<dyn AccountService>>,
+) -> Custom<Result<Json<models::Response<Vec<models::SessionData>>>, models::ErrorResponse>> {
+    match service.inner().sessions(auth_guard.account_id).await {
+        Ok(sessions) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(
+                sessions
+                    .iter()
+                    .map(|session| {
+                        models::SessionData::from_domain(session, &auth_guard.claims.session_id)
+                    })
+                    .collect::<Vec<_>>(),
+            ))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[delete("/sessions/<id>")]
+pub async fn delete_session(
+    auth_guard: SessionGuard,
+    id: String,
+    service: &State<Arc<dyn AccountService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    let request = DeleteSessionRequest {
+        account_id: auth_guard.account_id,
+        session_id: id,
+    };
+    match service.inner().delete_session(request).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/account/2fa/enroll")]
+pub async fn enroll_totp(
+    auth_guard: SessionGuard,
+    service: &State<Arc<dyn MfaService>>,
+) -> Custom<Result<Json<models::Response<models::TotpEnrollmentResponse>>, models::ErrorResponse>> {
+    match service.inner().enroll(auth_guard.account_id).await {
+        Ok(enrollment) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(
+                models::TotpEnrollmentResponse::from(enrollment),
+            ))),
         ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/account/2fa/confirm", data = "<request>")]
+pub async fn confirm_totp(
+    auth_guard: SessionGuard,
+    request: Json<models::ConfirmTotpRequest>,
+    service: &State<Arc<dyn MfaService>>,
+) -> Custom<Result<Json<models::Response<models::RecoveryCodesResponse>>, models::ErrorResponse>> {
+    let request = request.into_inner().into_domain(auth_guard.account_id);
+    match service.inner().confirm(request).await {
+        Ok(codes) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(
+                models::RecoveryCodesResponse::from(codes),
+            ))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/account/tokens", data = "<request>")]
+pub async fn post_access_token(
+    auth_guard: SessionGuard,
+    request: Json<models::CreateAccessTokenRequest>,
+    service: &State<Arc<dyn AccessTokenService>>,
+) -> Custom<Result<Json<models::Response<models::IssuedAccessTokenData>>, models::ErrorResponse>> {
+    let request = request.into_inner().into_domain(auth_guard.account_id);
+    match service.inner().issue(request).await {
+        Ok(issued) => Custom(
+            Status::Created,
+            Ok(Json(models::Response::from(
+                models::IssuedAccessTokenData::from(issued),
+            ))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[get("/account/tokens")]
+pub async fn get_access_tokens(
+    auth_guard: SessionGuard,
+    service: &State<Arc<dyn AccessTokenService>>,
+) -> Custom<Result<Json<models::Response<Vec<models::AccessTokenData>>>, models::ErrorResponse>> {
+    match service.inner().list(auth_guard.account_id).await {
+        Ok(tokens) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(
+                tokens
+                    .into_iter()
+                    .map(models::AccessTokenData::from)
+                    .collect::<Vec<_>>(),
+            ))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[delete("/account/tokens/<id>")]
+pub async fn delete_access_token(
+    auth_guard: SessionGuard,
+    id: i32,
+    service: &State<Arc<dyn AccessTokenService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    let request = RevokeAccessTokenRequest {
+        account_id: auth_guard.account_id,
+        id,
+    };
+    match service.inner().revoke(request).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
     }
 }
@@ -0,0 +1,71 @@
+use crate::{
+    domain::{AdminService, TodoListerAndCounter},
+    handler::{guards::AdminGuard, models},
+};
+use rocket::{State, http::Status, response::status::Custom, serde::json::Json};
+use std::sync::Arc;
+
+#[get("/admin/accounts?<filters..>")]
+pub async fn get_accounts(
+    _admin_guard: AdminGuard,
+    filters: models::GetAccountFilters,
+    service: &State<Arc<dyn AdminService>>,
+) -> Custom<Result<Json<models::Response<Vec<models::AccountData>>>, models::ErrorResponse>> {
+    match service.inner().accounts(filters.into()).await {
+        Ok((accounts, total)) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from((
+                accounts.iter().map(models::AccountData::from).collect(),
+                total,
+            )))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/admin/accounts/<id>/disable")]
+pub async fn disable_account(
+    admin_guard: AdminGuard,
+    id: i32,
+    service: &State<Arc<dyn AdminService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    match service.inner().disable(admin_guard.account_id, id).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/admin/accounts/<id>/enable")]
+pub async fn enable_account(
+    _admin_guard: AdminGuard,
+    id: i32,
+    service: &State<Arc<dyn AdminService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    match service.inner().enable(id).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[get("/admin/accounts/<id>/todo?<filters..>")]
+pub async fn get_account_todo(
+    _admin_guard: AdminGuard,
+    id: i32,
+    filters: models::GetTodoFilters,
+    lister: &State<Arc<dyn TodoListerAndCounter>>,
+) -> Custom<Result<Json<models::Response<Vec<models::TodoItemData>>>, models::ErrorResponse>> {
+    let filters = filters.into_domain(id);
+    match lister.inner().list(&filters).await {
+        Ok((items, total)) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from((
+                items
+                    .into_iter()
+                    .map(|item| models::TodoItemData::from(&item))
+                    .collect(),
+                total,
+            )))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
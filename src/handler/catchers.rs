@@ -0,0 +1,43 @@
+use rocket::{Request, http::Status};
+
+use crate::handler::models;
+
+#[catch(401)]
+pub fn unauthorized() -> models::ErrorResponse {
+    models::ErrorResponse::new(
+        Status::Unauthorized,
+        "unauthorized",
+        Some("a valid bearer token is required".to_string()),
+    )
+}
+
+#[catch(404)]
+pub fn not_found(request: &Request) -> models::ErrorResponse {
+    models::ErrorResponse::new(
+        Status::NotFound,
+        "not-found",
+        Some(format!(
+            "no route for {} {}",
+            request.method(),
+            request.uri()
+        )),
+    )
+}
+
+#[catch(422)]
+pub fn unprocessable_entity() -> models::ErrorResponse {
+    models::ErrorResponse::new(
+        Status::UnprocessableEntity,
+        "validation-failed",
+        Some("request body does not match the expected format".to_string()),
+    )
+}
+
+#[catch(default)]
+pub fn default(status: Status, _request: &Request) -> models::ErrorResponse {
+    models::ErrorResponse::new(
+        status,
+        &status.reason_lossy().to_lowercase().replace(' ', "-"),
+        None,
+    )
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{marker::PhantomData, net::IpAddr, sync::Arc};
 
 use rocket::{
     Request, State,
@@ -6,16 +6,85 @@ use rocket::{
     request::{FromRequest, Outcome},
 };
 
-use crate::domain::AccountService;
+use crate::domain::{
+    ACCESS_TOKEN_PREFIX, AccessToken, AccessTokenService, AccountService, AdminService,
+    AuthTokenClaims, ClientInfo, SCOPE_TODO_READ, SCOPE_TODO_WRITE,
+};
+
+#[derive(Clone)]
+pub enum Credential {
+    Session(AuthTokenClaims),
+    AccessToken(AccessToken),
+}
 
+#[derive(Clone)]
 pub struct AuthGuard {
     pub account_id: i32,
+    pub credential: Credential,
+}
+
+impl AuthGuard {
+    pub fn has_scope(&self, scope: &str) -> bool {
+        let scopes = match &self.credential {
+            Credential::Session(claims) => &claims.scopes,
+            Credential::AccessToken(access_token) => &access_token.scopes,
+        };
+        scopes.iter().any(|granted| granted == scope)
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum AuthError {
     Missing,
     Invalid,
+    Unverified,
+    SessionRequired,
+    InsufficientScope,
+    NotAdmin,
+}
+
+async fn authenticate(request: &Request<'_>) -> Result<AuthGuard, (Status, AuthError)> {
+    let auth_header = match request.headers().get_one("Authorization") {
+        Some(auth_header) => auth_header,
+        None => return Err((Status::Unauthorized, AuthError::Missing)),
+    };
+
+    let token = match auth_header.strip_prefix("Bearer ") {
+        Some(token) => token,
+        None => return Err((Status::Unauthorized, AuthError::Invalid)),
+    };
+
+    if token.starts_with(ACCESS_TOKEN_PREFIX) {
+        let access_token_service =
+            match request.guard::<&State<Arc<dyn AccessTokenService>>>().await {
+                Outcome::Success(service) => service,
+                _ => return Err((Status::Unauthorized, AuthError::Invalid)),
+            };
+        return match access_token_service
+            .inner()
+            .authorize(token.to_string())
+            .await
+        {
+            Ok(access_token) => Ok(AuthGuard {
+                account_id: access_token.account_id,
+                credential: Credential::AccessToken(access_token),
+            }),
+            _ => Err((Status::Unauthorized, AuthError::Invalid)),
+        };
+    }
+
+    let account_service = match request.guard::<&State<Arc<dyn AccountService>>>().await {
+        Outcome::Success(service) => service,
+        _ => return Err((Status::Unauthorized, AuthError::Invalid)),
+    };
+
+    match account_service.inner().authorize(token.to_string()).await {
+        Ok(claims) => Ok(AuthGuard {
+            account_id: claims.account_id,
+            credential: Credential::Session(claims),
+        }),
+        _ => Err((Status::Unauthorized, AuthError::Invalid)),
+    }
 }
 
 #[rocket::async_trait]
@@ -23,26 +92,193 @@ impl<'r> FromRequest<'r> for AuthGuard {
     type Error = AuthError;
 
     async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match request.local_cache_async(authenticate(request)).await {
+            Ok(auth_guard) => Outcome::Success(auth_guard.clone()),
+            Err(err) => Outcome::Error(*err),
+        }
+    }
+}
+
+pub struct SessionGuard {
+    pub account_id: i32,
+    pub claims: AuthTokenClaims,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for SessionGuard {
+    type Error = AuthError;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let auth_guard = match request.guard::<AuthGuard>().await {
+            Outcome::Success(auth_guard) => auth_guard,
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Forward(status) => return Outcome::Forward(status),
+        };
+
+        match auth_guard.credential {
+            Credential::Session(claims) => Outcome::Success(SessionGuard {
+                account_id: auth_guard.account_id,
+                claims,
+            }),
+            Credential::AccessToken(_) => {
+                Outcome::Error((Status::Forbidden, AuthError::SessionRequired))
+            }
+        }
+    }
+}
+
+pub trait Scope: Send + Sync + 'static {
+    const NAME: &'static str;
+}
+
+pub struct TodoRead;
+
+impl Scope for TodoRead {
+    const NAME: &'static str = SCOPE_TODO_READ;
+}
+
+pub struct TodoWrite;
+
+impl Scope for TodoWrite {
+    const NAME: &'static str = SCOPE_TODO_WRITE;
+}
+
+pub struct RequireScope<S: Scope> {
+    pub account_id: i32,
+    scope: PhantomData<S>,
+}
+
+#[rocket::async_trait]
+impl<'r, S: Scope> FromRequest<'r> for RequireScope<S> {
+    type Error = AuthError;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let auth_guard = match request.guard::<AuthGuard>().await {
+            Outcome::Success(auth_guard) => auth_guard,
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Forward(status) => return Outcome::Forward(status),
+        };
+
+        if !auth_guard.has_scope(S::NAME) {
+            return Outcome::Error((Status::Forbidden, AuthError::InsufficientScope));
+        }
+        Outcome::Success(RequireScope {
+            account_id: auth_guard.account_id,
+            scope: PhantomData,
+        })
+    }
+}
+
+pub struct VerifiedAuthGuard {
+    pub account_id: i32,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for VerifiedAuthGuard {
+    type Error = AuthError;
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let auth_guard = match request.guard::<AuthGuard>().await {
+            Outcome::Success(auth_guard) => auth_guard,
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Forward(status) => return Outcome::Forward(status),
+        };
+
         let account_service = match request.guard::<&State<Arc<dyn AccountService>>>().await {
             Outcome::Success(service) => service,
             _ => return Outcome::Error((Status::Unauthorized, AuthError::Invalid)),
         };
 
-        let auth_header = request.headers().get_one("Authorization");
-        if auth_header.is_none() {
-            return Outcome::Error((Status::Unauthorized, AuthError::Missing));
+        match account_service
+            .inner()
+            .require_verified(auth_guard.account_id)
+            .await
+        {
+            Ok(()) => Outcome::Success(VerifiedAuthGuard {
+                account_id: auth_guard.account_id,
+            }),
+            _ => Outcome::Error((Status::Forbidden, AuthError::Unverified)),
         }
+    }
+}
+
+pub struct AdminGuard {
+    pub account_id: i32,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AdminGuard {
+    type Error = AuthError;
 
-        let auth_header = auth_header.unwrap();
-        if !auth_header.starts_with("Bearer ") {
-            return Outcome::Error((Status::Unauthorized, AuthError::Invalid));
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let session_guard = match request.guard::<SessionGuard>().await {
+            Outcome::Success(session_guard) => session_guard,
+            Outcome::Error(err) => return Outcome::Error(err),
+            Outcome::Forward(status) => return Outcome::Forward(status),
+        };
+
+        let admin_service = match request.guard::<&State<Arc<dyn AdminService>>>().await {
+            Outcome::Success(service) => service,
+            _ => return Outcome::Error((Status::Unauthorized, AuthError::Invalid)),
+        };
+
+        match admin_service
+            .inner()
+            .require_admin(session_guard.account_id)
+            .await
+        {
+            Ok(()) => Outcome::Success(AdminGuard {
+                account_id: session_guard.account_id,
+            }),
+            _ => Outcome::Error((Status::Forbidden, AuthError::NotAdmin)),
         }
+    }
+}
 
-        let token = &auth_header[7..];
+#[derive(Clone, Copy, Default)]
+pub struct ClientIpConfig {
+    pub trust_proxy_header: bool,
+}
+
+impl ClientIpConfig {
+    pub fn client_ip(&self, request: &Request<'_>) -> Option<IpAddr> {
+        if self.trust_proxy_header {
+            request.client_ip()
+        } else {
+            request.remote().map(|remote| remote.ip())
+        }
+    }
+}
+
+pub struct ClientGuard {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ClientGuard {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        let ip_config = match request.guard::<&State<ClientIpConfig>>().await {
+            Outcome::Success(ip_config) => *ip_config.inner(),
+            _ => ClientIpConfig::default(),
+        };
+        Outcome::Success(ClientGuard {
+            user_agent: request
+                .headers()
+                .get_one("User-Agent")
+                .map(|user_agent| user_agent.to_string()),
+            ip_address: ip_config.client_ip(request).map(|ip| ip.to_string()),
+        })
+    }
+}
 
-        match account_service.inner().authorize(token.to_string()).await {
-            Ok(account_id) => Outcome::Success(AuthGuard { account_id }),
-            _ => Outcome::Error((Status::Unauthorized, AuthError::Invalid)),
+impl From<ClientGuard> for ClientInfo {
+    fn from(guard: ClientGuard) -> Self {
+        Self {
+            user_agent: guard.user_agent,
+            ip_address: guard.ip_address,
         }
     }
 }
@@ -1,5 +1,12 @@
 mod account;
+pub mod admin;
+pub mod catchers;
 pub mod models;
+pub mod project;
+pub mod rate_limit;
+pub mod tag;
 pub mod todo;
+pub mod well_known;
 pub use account::*;
 mod guards;
+pub use guards::ClientIpConfig;
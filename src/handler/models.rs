@@ -1,10 +1,30 @@
 use crate::domain::{
-    AuthToken, CreateTodoItemRequest as DomainCreateTodoItemRequest, Error, Filters,
-    LoginRequest as DomainLoginRequest, RegisterRequest as DomainRegisterRequest,
-    Status as TodoStatus, TodoItem, UpdateTodoItemRequest as DomainUpdateTodoItemRequest,
+    AccessToken, Account, AccountExport, AccountFilters, AuthTokenClaims,
+    ChangePasswordRequest as DomainChangePasswordRequest, ClientInfo,
+    ConfirmTotpRequest as DomainConfirmTotpRequest,
+    CreateProjectRequest as DomainCreateProjectRequest, CreateTagRequest as DomainCreateTagRequest,
+    CreateTodoItemRequest as DomainCreateTodoItemRequest,
+    DeleteAccountRequest as DomainDeleteAccountRequest, DeleteProjectMode,
+    DeleteProjectRequest as DomainDeleteProjectRequest, Error, FieldError, Filters,
+    ForgotPasswordRequest as DomainForgotPasswordRequest, IssueAccessTokenRequest,
+    IssuedAccessToken, JsonWebKey, LoginRequest as DomainLoginRequest, LoginResult, MfaChallenge,
+    MfaLoginRequest as DomainMfaLoginRequest, Priority as TodoPriority, Project, ProjectFilter,
+    RefreshRequest as DomainRefreshRequest, RegisterRequest as DomainRegisterRequest,
+    RenameProjectRequest as DomainRenameProjectRequest,
+    ResetPasswordRequest as DomainResetPasswordRequest, Session, Sort, SortDirection, SortField,
+    Status as TodoStatus, Tag, TagMatch, TodoItem, TokenPair, TotpEnrollment,
+    UpdateTagRequest as DomainUpdateTagRequest,
+    UpdateTodoItemRequest as DomainUpdateTodoItemRequest,
+    VerifyEmailRequest as DomainVerifyEmailRequest, VerifyMfaRequest,
+};
+use rocket::{
+    Request,
+    http::{ContentType, Header, Status},
+    response::{self, Responder},
+    serde::{Deserialize, Deserializer, Serialize, json::Json},
 };
-use rocket::serde::{Deserialize, Deserializer, Serialize};
 use std::str::FromStr;
+use time::format_description::well_known::Rfc3339;
 
 pub enum StatusQuery {
     None,
@@ -25,9 +45,9 @@ impl rocket::form::FromFormField<'_> for StatusQuery {
     }
 }
 
-impl Into<Option<TodoStatus>> for StatusQuery {
-    fn into(self) -> Option<TodoStatus> {
-        match self {
+impl From<StatusQuery> for Option<TodoStatus> {
+    fn from(query: StatusQuery) -> Self {
+        match query {
             StatusQuery::None => None,
             StatusQuery::Status(status) => Some(status),
         }
@@ -48,18 +68,128 @@ impl<'de> Deserialize<'de> for StatusField {
     }
 }
 
+pub struct PriorityField(TodoPriority);
+
+impl<'de> Deserialize<'de> for PriorityField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match TodoPriority::from_str(&String::deserialize(deserializer)?) {
+            Ok(priority) => Ok(PriorityField(priority)),
+            Err(err) => Err(rocket::serde::de::Error::custom(err)),
+        }
+    }
+}
+
+pub struct SortQuery(Vec<Sort>);
+
+impl FromStr for SortQuery {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut sort: Vec<Sort> = Vec::new();
+        for key in s.split(',') {
+            let (field, direction) = match key.trim().split_once(':') {
+                Some((field, direction)) => (field, SortDirection::from_str(direction)?),
+                None => (key.trim(), SortDirection::Asc),
+            };
+            let field = SortField::from_str(field)?;
+            if sort.iter().any(|key| key.field == field) {
+                return Err(format!("Duplicate sort field: {}", key.trim()));
+            }
+            sort.push(Sort { field, direction });
+        }
+        Ok(SortQuery(sort))
+    }
+}
+
+#[rocket::async_trait]
+impl rocket::form::FromFormField<'_> for SortQuery {
+    fn from_value(field: rocket::form::ValueField<'_>) -> rocket::form::Result<'_, Self> {
+        SortQuery::from_str(field.value).map_err(|err| rocket::form::Error::validation(err).into())
+    }
+}
+
+#[derive(FromFormField)]
+pub enum TagMatchQuery {
+    Any,
+    All,
+}
+
+impl From<TagMatchQuery> for TagMatch {
+    fn from(query: TagMatchQuery) -> Self {
+        match query {
+            TagMatchQuery::Any => TagMatch::Any,
+            TagMatchQuery::All => TagMatch::All,
+        }
+    }
+}
+
+pub struct ProjectQuery(ProjectFilter);
+
+#[rocket::async_trait]
+impl rocket::form::FromFormField<'_> for ProjectQuery {
+    fn from_value(field: rocket::form::ValueField<'_>) -> rocket::form::Result<'_, Self> {
+        if field.value == "none" {
+            return Ok(ProjectQuery(ProjectFilter::Unassigned));
+        }
+        match field.value.parse::<i32>() {
+            Ok(project_id) => Ok(ProjectQuery(ProjectFilter::Project(project_id))),
+            Err(_) => Err(rocket::form::Error::validation(
+                "project must be a project id or 'none'",
+            )
+            .into()),
+        }
+    }
+}
+
+#[derive(FromFormField)]
+pub enum DeleteProjectModeQuery {
+    Move,
+    Cascade,
+}
+
+pub struct TimestampField(time::OffsetDateTime);
+
+impl<'de> Deserialize<'de> for TimestampField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match time::OffsetDateTime::parse(&String::deserialize(deserializer)?, &Rfc3339) {
+            Ok(timestamp) => Ok(TimestampField(timestamp)),
+            Err(err) => Err(rocket::serde::de::Error::custom(err)),
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl rocket::form::FromFormField<'_> for TimestampField {
+    fn from_value(field: rocket::form::ValueField<'_>) -> rocket::form::Result<'_, Self> {
+        match time::OffsetDateTime::parse(field.value, &Rfc3339) {
+            Ok(timestamp) => Ok(TimestampField(timestamp)),
+            Err(_) => Err(rocket::form::Error::validation("Invalid RFC 3339 timestamp").into()),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(crate = "rocket::serde")]
 pub struct RegisterRequest {
     pub login: String,
     pub password: String,
+    #[serde(default)]
+    pub email: Option<String>,
 }
 
-impl Into<DomainRegisterRequest> for RegisterRequest {
-    fn into(self) -> DomainRegisterRequest {
+impl RegisterRequest {
+    pub fn into_domain(self, client: ClientInfo) -> DomainRegisterRequest {
         DomainRegisterRequest {
             login: self.login,
             password: self.password,
+            email: self.email,
+            client,
         }
     }
 }
@@ -69,13 +199,108 @@ impl Into<DomainRegisterRequest> for RegisterRequest {
 pub struct LoginRequest {
     pub login: String,
     pub password: String,
+    #[serde(default)]
+    pub scopes: Option<Vec<String>>,
 }
 
-impl Into<DomainLoginRequest> for LoginRequest {
-    fn into(self) -> DomainLoginRequest {
+impl LoginRequest {
+    pub fn into_domain(self, client: ClientInfo) -> DomainLoginRequest {
         DomainLoginRequest {
             login: self.login,
             password: self.password,
+            scopes: self.scopes,
+            client,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
+impl From<RefreshRequest> for DomainRefreshRequest {
+    fn from(request: RefreshRequest) -> Self {
+        Self {
+            refresh_token: request.refresh_token,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ChangePasswordRequest {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+impl ChangePasswordRequest {
+    pub fn into_domain(self, claims: &AuthTokenClaims) -> DomainChangePasswordRequest {
+        DomainChangePasswordRequest {
+            account_id: claims.account_id,
+            session_id: claims.session_id.clone(),
+            current_password: self.current_password,
+            new_password: self.new_password,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct DeleteAccountRequest {
+    pub password: String,
+}
+
+impl DeleteAccountRequest {
+    pub fn into_domain(self, account_id: i32) -> DomainDeleteAccountRequest {
+        DomainDeleteAccountRequest {
+            account_id,
+            password: self.password,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ForgotPasswordRequest {
+    pub login: String,
+}
+
+impl From<ForgotPasswordRequest> for DomainForgotPasswordRequest {
+    fn from(request: ForgotPasswordRequest) -> Self {
+        Self {
+            login: request.login,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ResetPasswordRequest {
+    pub token: String,
+    pub new_password: String,
+}
+
+impl From<ResetPasswordRequest> for DomainResetPasswordRequest {
+    fn from(request: ResetPasswordRequest) -> Self {
+        Self {
+            token: request.token,
+            new_password: request.new_password,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct VerifyEmailRequest {
+    pub token: String,
+}
+
+impl From<VerifyEmailRequest> for DomainVerifyEmailRequest {
+    fn from(request: VerifyEmailRequest) -> Self {
+        Self {
+            token: request.token,
         }
     }
 }
@@ -86,14 +311,272 @@ pub struct AuthTokenResponse {
     access_token: String,
     token_type: String,
     expires_in: usize,
+    refresh_token: String,
+    refresh_expires_in: usize,
 }
 
-impl From<AuthToken> for AuthTokenResponse {
-    fn from(token: AuthToken) -> Self {
+impl From<TokenPair> for AuthTokenResponse {
+    fn from(tokens: TokenPair) -> Self {
+        let now = time::OffsetDateTime::now_utc();
         Self {
-            access_token: token.token,
+            access_token: tokens.access_token.token,
             token_type: "bearer".to_string(),
-            expires_in: (token.expires - time::OffsetDateTime::now_utc()).whole_seconds() as usize,
+            expires_in: (tokens.access_token.expires - now).whole_seconds() as usize,
+            refresh_token: tokens.refresh_token,
+            refresh_expires_in: (tokens.refresh_expires - now).whole_seconds() as usize,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct MfaChallengeResponse {
+    mfa_required: bool,
+    mfa_token: String,
+    expires_in: usize,
+}
+
+impl From<MfaChallenge> for MfaChallengeResponse {
+    fn from(challenge: MfaChallenge) -> Self {
+        Self {
+            mfa_required: true,
+            mfa_token: challenge.token,
+            expires_in: (challenge.expires - time::OffsetDateTime::now_utc()).whole_seconds()
+                as usize,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde", untagged)]
+pub enum LoginResponse {
+    Authenticated(AuthTokenResponse),
+    MfaRequired(MfaChallengeResponse),
+}
+
+impl From<LoginResult> for LoginResponse {
+    fn from(result: LoginResult) -> Self {
+        match result {
+            LoginResult::Authenticated(tokens) => Self::Authenticated(tokens.into()),
+            LoginResult::MfaRequired(challenge) => Self::MfaRequired(challenge.into()),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct MfaLoginRequest {
+    pub mfa_token: String,
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub recovery_code: Option<String>,
+}
+
+impl MfaLoginRequest {
+    pub fn into_domain(self, client: ClientInfo) -> DomainMfaLoginRequest {
+        DomainMfaLoginRequest {
+            verification: VerifyMfaRequest {
+                mfa_token: self.mfa_token,
+                code: self.code,
+                recovery_code: self.recovery_code,
+                ip_address: client.ip_address.clone(),
+            },
+            client,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct TotpEnrollmentResponse {
+    secret: String,
+    otpauth_uri: String,
+}
+
+impl From<TotpEnrollment> for TotpEnrollmentResponse {
+    fn from(enrollment: TotpEnrollment) -> Self {
+        Self {
+            secret: enrollment.secret,
+            otpauth_uri: enrollment.uri,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ConfirmTotpRequest {
+    pub code: String,
+}
+
+impl ConfirmTotpRequest {
+    pub fn into_domain(self, account_id: i32) -> DomainConfirmTotpRequest {
+        DomainConfirmTotpRequest {
+            account_id,
+            code: self.code,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RecoveryCodesResponse {
+    recovery_codes: Vec<String>,
+}
+
+impl From<Vec<String>> for RecoveryCodesResponse {
+    fn from(recovery_codes: Vec<String>) -> Self {
+        Self { recovery_codes }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CreateAccessTokenRequest {
+    pub name: String,
+    pub scopes: Vec<String>,
+    #[serde(default)]
+    pub expires_in: Option<i64>,
+}
+
+impl CreateAccessTokenRequest {
+    pub fn into_domain(self, account_id: i32) -> IssueAccessTokenRequest {
+        IssueAccessTokenRequest {
+            account_id,
+            name: self.name,
+            scopes: self.scopes,
+            duration: self.expires_in.map(time::Duration::seconds),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct AccessTokenData {
+    pub id: i32,
+    pub name: String,
+    pub scopes: Vec<String>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub last_used_at: Option<time::OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub expires_at: Option<time::OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: time::OffsetDateTime,
+}
+
+impl From<AccessToken> for AccessTokenData {
+    fn from(token: AccessToken) -> Self {
+        Self {
+            id: token.id,
+            name: token.name,
+            scopes: token.scopes,
+            last_used_at: token.last_used_at,
+            expires_at: token.expires_at,
+            created_at: token.created_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct IssuedAccessTokenData {
+    #[serde(flatten)]
+    pub token: AccessTokenData,
+    pub secret: String,
+}
+
+impl From<IssuedAccessToken> for IssuedAccessTokenData {
+    fn from(issued: IssuedAccessToken) -> Self {
+        Self {
+            token: issued.token.into(),
+            secret: issued.secret,
+        }
+    }
+}
+
+#[derive(FromForm)]
+pub struct GetAccountFilters {
+    #[field(default = Some(10))]
+    pub limit: Option<i32>,
+    pub offset: Option<i32>,
+}
+
+impl From<GetAccountFilters> for AccountFilters {
+    fn from(filters: GetAccountFilters) -> Self {
+        Self {
+            limit: filters.limit,
+            offset: filters.offset,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct AccountData {
+    pub id: i32,
+    pub login: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub role: String,
+    pub two_factor_enabled: bool,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub disabled_at: Option<time::OffsetDateTime>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: time::OffsetDateTime,
+}
+
+impl From<&Account> for AccountData {
+    fn from(account: &Account) -> Self {
+        Self {
+            id: account.id,
+            login: account.login.clone(),
+            email: account.email.clone(),
+            email_verified: account.email_verified_at.is_some(),
+            role: account.role.to_string(),
+            two_factor_enabled: account.totp_enabled_at.is_some(),
+            disabled_at: account.disabled_at,
+            created_at: account.created_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct AccountExportData {
+    pub account: AccountData,
+    pub todo_items: Vec<TodoItemData>,
+    pub projects: Vec<ProjectData>,
+    pub tags: Vec<TagData>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub exported_at: time::OffsetDateTime,
+}
+
+impl From<AccountExport> for AccountExportData {
+    fn from(export: AccountExport) -> Self {
+        Self {
+            account: AccountData::from(&export.account),
+            todo_items: export.todo_items.iter().map(TodoItemData::from).collect(),
+            projects: export.projects.iter().map(ProjectData::from).collect(),
+            tags: export.tags.iter().map(TagData::from).collect(),
+            exported_at: export.exported_at,
+        }
+    }
+}
+
+#[derive(Responder)]
+pub struct Attachment<R> {
+    inner: R,
+    content_disposition: Header<'static>,
+}
+
+impl<R> Attachment<R> {
+    pub fn new(inner: R, filename: &str) -> Self {
+        Self {
+            inner,
+            content_disposition: Header::new(
+                "Content-Disposition",
+                format!("attachment; filename=\"{}\"", filename),
+            ),
         }
     }
 }
@@ -101,15 +584,35 @@ impl From<AuthToken> for AuthTokenResponse {
 #[derive(FromForm)]
 pub struct GetTodoFilters {
     pub status: StatusQuery,
+    #[field(default = false)]
+    pub trashed: bool,
+    pub project: Option<ProjectQuery>,
+    pub due_before: Option<TimestampField>,
+    pub due_after: Option<TimestampField>,
+    #[field(default = false)]
+    pub overdue: bool,
+    pub tag: Vec<String>,
+    #[field(default = TagMatchQuery::Any)]
+    pub tag_match: TagMatchQuery,
+    pub sort: Option<SortQuery>,
     #[field(default = Some(10))]
     pub limit: Option<i32>,
     pub offset: Option<i32>,
 }
 
-impl Into<Filters> for GetTodoFilters {
-    fn into(self) -> Filters {
+impl GetTodoFilters {
+    pub fn into_domain(self, owner_id: i32) -> Filters {
         Filters {
+            owner_id,
             status: self.status.into(),
+            trashed: self.trashed,
+            project: self.project.map(|project| project.0),
+            due_before: self.due_before.map(|due_before| due_before.0),
+            due_after: self.due_after.map(|due_after| due_after.0),
+            overdue: self.overdue,
+            tags: self.tag,
+            tag_match: self.tag_match.into(),
+            sort: self.sort.map(|sort| sort.0).unwrap_or_default(),
             limit: self.limit,
             offset: self.offset,
         }
@@ -121,14 +624,23 @@ impl Into<Filters> for GetTodoFilters {
 pub struct CreateTodoItemRequest {
     pub title: String,
     pub description: String,
+    #[serde(default)]
+    pub priority: Option<PriorityField>,
+    #[serde(default)]
+    pub due_at: Option<TimestampField>,
+    #[serde(default)]
+    pub project_id: Option<i32>,
 }
 
 impl CreateTodoItemRequest {
     pub fn into_domain(self, owner_id: i32) -> DomainCreateTodoItemRequest {
         DomainCreateTodoItemRequest {
-            owner_id: owner_id,
+            owner_id,
+            project_id: self.project_id,
             title: self.title,
             description: self.description,
+            priority: self.priority.map(|priority| priority.0).unwrap_or_default(),
+            due_at: self.due_at.map(|due_at| due_at.0),
         }
     }
 }
@@ -136,7 +648,18 @@ impl CreateTodoItemRequest {
 #[derive(Deserialize)]
 #[serde(crate = "rocket::serde")]
 pub struct UpdateTodoItemRequest {
-    pub status: StatusField,
+    #[serde(default, deserialize_with = "deserialize_non_null")]
+    pub title: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub description: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_non_null")]
+    pub status: Option<StatusField>,
+    #[serde(default, deserialize_with = "deserialize_non_null")]
+    pub priority: Option<PriorityField>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub due_at: Option<Option<TimestampField>>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub project_id: Option<Option<i32>>,
 }
 
 impl UpdateTodoItemRequest {
@@ -144,11 +667,195 @@ impl UpdateTodoItemRequest {
         DomainUpdateTodoItemRequest {
             owner_id,
             item_id,
-            status: self.status.0,
+            title: self.title,
+            description: self
+                .description
+                .map(|description| description.unwrap_or_default()),
+            status: self.status.map(|status| status.0),
+            priority: self.priority.map(|priority| priority.0),
+            due_at: self.due_at.map(|due_at| due_at.map(|due_at| due_at.0)),
+            project_id: self.project_id,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CreateTagRequest {
+    pub name: String,
+    #[serde(default)]
+    pub color: Option<String>,
+}
+
+impl CreateTagRequest {
+    pub fn into_domain(self, owner_id: i32) -> DomainCreateTagRequest {
+        DomainCreateTagRequest {
+            owner_id,
+            name: self.name,
+            color: self.color,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct UpdateTagRequest {
+    #[serde(default, deserialize_with = "deserialize_non_null")]
+    pub name: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    pub color: Option<Option<String>>,
+}
+
+impl UpdateTagRequest {
+    pub fn into_domain(self, tag_id: i32, owner_id: i32) -> DomainUpdateTagRequest {
+        DomainUpdateTagRequest {
+            owner_id,
+            tag_id,
+            name: self.name,
+            color: self.color,
         }
     }
 }
 
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct TagData {
+    pub id: i32,
+    pub name: String,
+    pub color: Option<String>,
+
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: time::OffsetDateTime,
+
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: time::OffsetDateTime,
+}
+
+impl From<&Tag> for TagData {
+    fn from(model: &Tag) -> Self {
+        Self {
+            id: model.id,
+            name: model.name.clone(),
+            color: model.color.clone(),
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CreateProjectRequest {
+    pub name: String,
+}
+
+impl CreateProjectRequest {
+    pub fn into_domain(self, owner_id: i32) -> DomainCreateProjectRequest {
+        DomainCreateProjectRequest {
+            owner_id,
+            name: self.name,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RenameProjectRequest {
+    pub name: String,
+}
+
+impl RenameProjectRequest {
+    pub fn into_domain(self, project_id: i32, owner_id: i32) -> DomainRenameProjectRequest {
+        DomainRenameProjectRequest {
+            owner_id,
+            project_id,
+            name: self.name,
+        }
+    }
+}
+
+#[derive(FromForm)]
+pub struct DeleteProjectQuery {
+    #[field(default = DeleteProjectModeQuery::Move)]
+    pub mode: DeleteProjectModeQuery,
+    pub target: Option<i32>,
+}
+
+impl DeleteProjectQuery {
+    pub fn into_domain(
+        self,
+        project_id: i32,
+        owner_id: i32,
+    ) -> Result<DomainDeleteProjectRequest, Error> {
+        let mode = match self.mode {
+            DeleteProjectModeQuery::Move => DeleteProjectMode::Move {
+                target_id: self.target,
+            },
+            DeleteProjectModeQuery::Cascade if self.target.is_some() => {
+                return Err(Error::validation(
+                    "target",
+                    "can only be used when moving items",
+                ));
+            }
+            DeleteProjectModeQuery::Cascade => DeleteProjectMode::Cascade,
+        };
+        Ok(DomainDeleteProjectRequest {
+            owner_id,
+            project_id,
+            mode,
+        })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ProjectData {
+    pub id: i32,
+    pub name: String,
+    pub archived: bool,
+
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub archived_at: Option<time::OffsetDateTime>,
+
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: time::OffsetDateTime,
+
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: time::OffsetDateTime,
+}
+
+impl From<&Project> for ProjectData {
+    fn from(model: &Project) -> Self {
+        Self {
+            id: model.id,
+            name: model.name.clone(),
+            archived: model.is_archived(),
+            archived_at: model.archived_at,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+fn deserialize_non_null<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    match Option::<T>::deserialize(deserializer)? {
+        Some(value) => Ok(Some(value)),
+        None => Err(rocket::serde::de::Error::custom("value must not be null")),
+    }
+}
+
+fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
 #[derive(Serialize)]
 #[serde(crate = "rocket::serde")]
 pub struct Response<T> {
@@ -170,7 +877,7 @@ impl<T> From<(T, i64)> for Response<T> {
     fn from((item, total): (T, i64)) -> Self {
         Self {
             data: item,
-            meta: Some(Meta { total: total }),
+            meta: Some(Meta { total }),
         }
     }
 }
@@ -185,8 +892,10 @@ pub struct Meta {
 #[serde(crate = "rocket::serde")]
 pub struct TodoItemData {
     pub id: i32,
+    pub project_id: Option<i32>,
     pub title: String,
     pub status: String,
+    pub priority: String,
     pub description: String,
 
     #[serde(with = "time::serde::rfc3339")]
@@ -194,17 +903,63 @@ pub struct TodoItemData {
 
     #[serde(with = "time::serde::rfc3339")]
     pub updated_at: time::OffsetDateTime,
+
+    #[serde(
+        with = "time::serde::rfc3339::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub deleted_at: Option<time::OffsetDateTime>,
+
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub due_at: Option<time::OffsetDateTime>,
+
+    pub tags: Vec<TagData>,
 }
 
 impl From<&TodoItem> for TodoItemData {
     fn from(model: &TodoItem) -> Self {
         Self {
             id: model.id,
+            project_id: model.project_id,
             title: model.title.clone(),
             status: model.status.to_string(),
+            priority: model.priority.to_string(),
             description: model.description.clone(),
             created_at: model.created_at,
             updated_at: model.updated_at,
+            deleted_at: model.deleted_at,
+            due_at: model.due_at,
+            tags: model.tags.iter().map(TagData::from).collect(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct SessionData {
+    pub id: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub scopes: Vec<String>,
+    pub current: bool,
+
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: time::OffsetDateTime,
+
+    #[serde(with = "time::serde::rfc3339")]
+    pub last_seen_at: time::OffsetDateTime,
+}
+
+impl SessionData {
+    pub fn from_domain(model: &Session, current_session_id: &str) -> Self {
+        Self {
+            id: model.id.clone(),
+            user_agent: model.user_agent.clone(),
+            ip_address: model.ip_address.clone(),
+            scopes: model.scopes.clone(),
+            current: model.id == current_session_id,
+            created_at: model.created_at,
+            last_seen_at: model.last_seen_at,
         }
     }
 }
@@ -212,43 +967,163 @@ impl From<&TodoItem> for TodoItemData {
 #[derive(Serialize)]
 #[serde(crate = "rocket::serde")]
 pub struct ErrorResponse {
-    error: ErrorData,
+    #[serde(rename = "type")]
+    problem_type: String,
+    title: String,
+    status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<FieldErrorData>,
+    #[serde(skip)]
+    retry_after: Option<i64>,
+}
+
+impl ErrorResponse {
+    pub fn new(status: Status, code: &str, detail: Option<String>) -> Self {
+        Self {
+            problem_type: format!("/problems/{}", code),
+            title: status.reason_lossy().to_string(),
+            status: status.code,
+            detail,
+            errors: Vec::new(),
+            retry_after: None,
+        }
+    }
 }
 
 impl From<&Error> for ErrorResponse {
     fn from(error: &Error) -> Self {
+        let status = Status::from(error);
         match error {
-            Error::NotFound(_) => Self {
-                error: ErrorData {
-                    code: "not_found".to_string(),
-                },
-            },
-            Error::OperationNotApplicable(_) => Self {
-                error: ErrorData {
-                    code: "operation_not_applicable".to_string(),
-                },
+            Error::NotFound(_) => Self::new(status, "not-found", None),
+            Error::Unauthorized(detail) => Self::new(status, "unauthorized", Some(detail.clone())),
+            Error::Forbidden(detail) => Self::new(status, "forbidden", Some(detail.clone())),
+            Error::OperationNotApplicable(detail) => {
+                Self::new(status, "operation-not-applicable", Some(detail.clone()))
+            }
+            Error::Conflict(detail) => Self::new(status, "conflict", Some(detail.clone())),
+            Error::Validation(errors) => Self {
+                errors: errors.iter().map(FieldErrorData::from).collect(),
+                ..Self::new(
+                    status,
+                    "validation-failed",
+                    Some("request contains invalid fields".to_string()),
+                )
             },
-            _ => Self {
-                error: ErrorData {
-                    code: "internal_server_error".to_string(),
-                },
+            Error::RateLimited(retry_after) => Self {
+                retry_after: Some((retry_after.as_seconds_f64().ceil() as i64).max(1)),
+                ..Self::new(status, "rate-limited", None)
             },
+            Error::Unknown(_) => Self::new(status, "internal-server-error", None),
+        }
+    }
+}
+
+impl<'r> Responder<'r, 'static> for ErrorResponse {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+        let status = Status::new(self.status);
+        let retry_after = self.retry_after;
+        let mut response = rocket::Response::build_from(Json(self).respond_to(request)?);
+        response
+            .status(status)
+            .header(ContentType::new("application", "problem+json"));
+        if status == Status::Unauthorized {
+            response.header(Header::new("WWW-Authenticate", "Bearer"));
+        }
+        if let Some(retry_after) = retry_after {
+            response.header(Header::new("Retry-After", retry_after.to_string()));
         }
+        response.ok()
     }
 }
 
 #[derive(Serialize)]
 #[serde(crate = "rocket::serde")]
-pub struct ErrorData {
-    pub code: String,
+pub struct FieldErrorData {
+    pub field: String,
+    pub message: String,
+}
+
+impl From<&FieldError> for FieldErrorData {
+    fn from(error: &FieldError) -> Self {
+        Self {
+            field: error.field.clone(),
+            message: error.message.clone(),
+        }
+    }
 }
 
-impl From<&Error> for rocket::http::Status {
+impl From<&Error> for Status {
     fn from(error: &Error) -> Self {
         match error {
-            Error::NotFound(_) => rocket::http::Status::NotFound,
-            Error::OperationNotApplicable(_) => rocket::http::Status::BadRequest,
-            _ => rocket::http::Status::InternalServerError,
+            Error::NotFound(_) => Status::NotFound,
+            Error::Unauthorized(_) => Status::Unauthorized,
+            Error::Forbidden(_) => Status::Forbidden,
+            Error::OperationNotApplicable(_) => Status::BadRequest,
+            Error::Conflict(_) => Status::Conflict,
+            Error::Validation(_) => Status::UnprocessableEntity,
+            Error::RateLimited(_) => Status::TooManyRequests,
+            Error::Unknown(_) => Status::InternalServerError,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JsonWebKeyData {
+    kty: String,
+    #[serde(rename = "use")]
+    key_use: String,
+    alg: String,
+    kid: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    e: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<String>,
+}
+
+impl From<JsonWebKey> for JsonWebKeyData {
+    fn from(key: JsonWebKey) -> Self {
+        match key {
+            JsonWebKey::Rsa { kid, n, e } => Self {
+                kty: "RSA".to_string(),
+                key_use: "sig".to_string(),
+                alg: "RS256".to_string(),
+                kid,
+                n: Some(n),
+                e: Some(e),
+                crv: None,
+                x: None,
+            },
+            JsonWebKey::Ed25519 { kid, x } => Self {
+                kty: "OKP".to_string(),
+                key_use: "sig".to_string(),
+                alg: "EdDSA".to_string(),
+                kid,
+                n: None,
+                e: None,
+                crv: Some("Ed25519".to_string()),
+                x: Some(x),
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JsonWebKeySet {
+    keys: Vec<JsonWebKeyData>,
+}
+
+impl From<Vec<JsonWebKey>> for JsonWebKeySet {
+    fn from(keys: Vec<JsonWebKey>) -> Self {
+        Self {
+            keys: keys.into_iter().map(JsonWebKeyData::from).collect(),
         }
     }
 }
@@ -0,0 +1,131 @@
+use crate::{
+    domain::{ArchiveProjectRequest, ProjectFilters, ProjectService},
+    handler::{
+        guards::{RequireScope, TodoRead, TodoWrite},
+        models,
+    },
+};
+use rocket::{State, http::Status, response::status::Custom, serde::json::Json};
+use std::sync::Arc;
+
+#[post("/projects", data = "<request>")]
+pub async fn post_project(
+    auth_guard: RequireScope<TodoWrite>,
+    request: Json<models::CreateProjectRequest>,
+    service: &State<Arc<dyn ProjectService>>,
+) -> Custom<Result<Json<models::Response<models::ProjectData>>, models::ErrorResponse>> {
+    let request = request.into_inner().into_domain(auth_guard.account_id);
+    match service.inner().create(request).await {
+        Ok(project) => Custom(
+            Status::Created,
+            Ok(Json(models::Response::from(models::ProjectData::from(
+                &project,
+            )))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[get("/projects?<archived>")]
+pub async fn get_projects(
+    auth_guard: RequireScope<TodoRead>,
+    archived: Option<bool>,
+    service: &State<Arc<dyn ProjectService>>,
+) -> Custom<Result<Json<models::Response<Vec<models::ProjectData>>>, models::ErrorResponse>> {
+    let filters = ProjectFilters {
+        owner_id: auth_guard.account_id,
+        archived: archived.unwrap_or(false),
+    };
+    match service.inner().list(filters).await {
+        Ok(projects) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(
+                projects
+                    .iter()
+                    .map(models::ProjectData::from)
+                    .collect::<Vec<_>>(),
+            ))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[patch("/projects/<id>", data = "<request>")]
+pub async fn patch_project(
+    auth_guard: RequireScope<TodoWrite>,
+    id: i32,
+    request: Json<models::RenameProjectRequest>,
+    service: &State<Arc<dyn ProjectService>>,
+) -> Custom<Result<Json<models::Response<models::ProjectData>>, models::ErrorResponse>> {
+    let request = request.into_inner().into_domain(id, auth_guard.account_id);
+    match service.inner().rename(request).await {
+        Ok(project) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(models::ProjectData::from(
+                &project,
+            )))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/projects/<id>/archive")]
+pub async fn archive_project(
+    auth_guard: RequireScope<TodoWrite>,
+    id: i32,
+    service: &State<Arc<dyn ProjectService>>,
+) -> Custom<Result<Json<models::Response<models::ProjectData>>, models::ErrorResponse>> {
+    let request = ArchiveProjectRequest {
+        owner_id: auth_guard.account_id,
+        project_id: id,
+        archived: true,
+    };
+    match service.inner().archive(request).await {
+        Ok(project) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(models::ProjectData::from(
+                &project,
+            )))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/projects/<id>/unarchive")]
+pub async fn unarchive_project(
+    auth_guard: RequireScope<TodoWrite>,
+    id: i32,
+    service: &State<Arc<dyn ProjectService>>,
+) -> Custom<Result<Json<models::Response<models::ProjectData>>, models::ErrorResponse>> {
+    let request = ArchiveProjectRequest {
+        owner_id: auth_guard.account_id,
+        project_id: id,
+        archived: false,
+    };
+    match service.inner().archive(request).await {
+        Ok(project) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(models::ProjectData::from(
+                &project,
+            )))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[delete("/projects/<id>?<query..>")]
+pub async fn delete_project(
+    auth_guard: RequireScope<TodoWrite>,
+    id: i32,
+    query: models::DeleteProjectQuery,
+    service: &State<Arc<dyn ProjectService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    let request = match query.into_domain(id, auth_guard.account_id) {
+        Ok(request) => request,
+        Err(err) => return Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    };
+    match service.inner().delete(request).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use rocket::{
+    Data, Request, Response,
+    fairing::{Fairing, Info, Kind},
+    http::{Header, Method, uri::Origin},
+};
+
+use crate::{
+    domain::{
+        ACCESS_TOKEN_PREFIX, AuthTokenGenerator, Error, RateLimit, RateLimitDecision,
+        RateLimitStore,
+    },
+    handler::{guards::ClientIpConfig, models},
+};
+
+const RATE_LIMITED_PATH: &str = "/rate-limited";
+
+pub struct RateLimitRule {
+    name: String,
+    method: Method,
+    segments: Vec<String>,
+    limit: RateLimit,
+}
+
+impl RateLimitRule {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let invalid = || {
+            format!(
+                "invalid rate limit rule, expected METHOD /path=capacity/seconds: {}",
+                spec
+            )
+        };
+        let (route, limit) = spec.split_once('=').ok_or_else(invalid)?;
+        let (method, path) = route.trim().split_once(' ').ok_or_else(invalid)?;
+        let (capacity, period) = limit.trim().split_once('/').ok_or_else(invalid)?;
+        let method = method.parse::<Method>().map_err(|_| invalid())?;
+        let capacity = capacity.parse::<u32>().map_err(|_| invalid())?;
+        let period = period.parse::<i64>().map_err(|_| invalid())?;
+        if capacity == 0 || period <= 0 {
+            return Err(invalid());
+        }
+        Ok(Self {
+            name: format!("{} {}", method, path.trim()),
+            method,
+            segments: path
+                .trim()
+                .split('/')
+                .filter(|segment| !segment.is_empty())
+                .map(str::to_string)
+                .collect(),
+            limit: RateLimit {
+                capacity,
+                period: time::Duration::seconds(period),
+            },
+        })
+    }
+
+    pub fn period(&self) -> time::Duration {
+        self.limit.period
+    }
+
+    fn matches(&self, request: &Request<'_>) -> bool {
+        if request.method() != self.method {
+            return false;
+        }
+        let segments: Vec<&str> = request
+            .uri()
+            .path()
+            .segments()
+            .filter(|segment| !segment.is_empty())
+            .collect();
+        segments.len() == self.segments.len()
+            && self
+                .segments
+                .iter()
+                .zip(segments)
+                .all(|(expected, actual)| expected == "*" || expected == actual)
+    }
+}
+
+pub struct RateLimiter {
+    store: Arc<dyn RateLimitStore>,
+    default_limit: RateLimit,
+    rules: Vec<RateLimitRule>,
+    token_generator: Arc<dyn AuthTokenGenerator>,
+    ip_config: ClientIpConfig,
+}
+
+impl RateLimiter {
+    pub fn new(
+        store: Arc<dyn RateLimitStore>,
+        default_limit: RateLimit,
+        rules: Vec<RateLimitRule>,
+        token_generator: Arc<dyn AuthTokenGenerator>,
+        ip_config: ClientIpConfig,
+    ) -> Self {
+        Self {
+            store,
+            default_limit,
+            rules,
+            token_generator,
+            ip_config,
+        }
+    }
+
+    pub fn parse_rules(spec: &str) -> Result<Vec<RateLimitRule>, String> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|rule| !rule.is_empty())
+            .map(RateLimitRule::parse)
+            .collect()
+    }
+
+    // Only the token signature is checked here, so rejected requests cost no DB round-trips.
+    // Personal access tokens need a DB lookup and are keyed by IP instead.
+    fn client_key(&self, request: &Request<'_>) -> String {
+        let claims = request
+            .headers()
+            .get_one("Authorization")
+            .and_then(|auth_header| auth_header.strip_prefix("Bearer "))
+            .filter(|token| !token.starts_with(ACCESS_TOKEN_PREFIX))
+            .and_then(|token| self.token_generator.parse(token.to_string()).ok());
+        match claims {
+            Some(claims) => format!("account:{}", claims.account_id),
+            None => match self.ip_config.client_ip(request) {
+                Some(ip) => format!("ip:{}", ip),
+                None => "ip:unknown".to_string(),
+            },
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for RateLimiter {
+    fn info(&self) -> Info {
+        Info {
+            name: "Rate limiter",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _: &mut Data<'_>) {
+        let (name, limit) = match self.rules.iter().find(|rule| rule.matches(request)) {
+            Some(rule) => (rule.name.as_str(), &rule.limit),
+            None => ("default", &self.default_limit),
+        };
+        let key = format!("{}|{}", name, self.client_key(request));
+
+        match self.store.take(key, limit).await {
+            Ok(decision) => {
+                if !decision.allowed {
+                    request.set_method(Method::Get);
+                    request.set_uri(Origin::const_new(RATE_LIMITED_PATH, None));
+                }
+                request.local_cache(|| Some(decision));
+            }
+            Err(err) => error!("Rate limiter failed, allowing request: {:?}", err),
+        }
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        let decision = match request.local_cache(|| None::<RateLimitDecision>) {
+            Some(decision) => decision,
+            None => return,
+        };
+        response.set_header(Header::new("RateLimit-Limit", decision.limit.to_string()));
+        response.set_header(Header::new(
+            "RateLimit-Remaining",
+            decision.remaining.to_string(),
+        ));
+        response.set_header(Header::new(
+            "RateLimit-Reset",
+            (decision.reset.as_seconds_f64().ceil() as i64).to_string(),
+        ));
+        if !decision.allowed {
+            response.set_header(Header::new(
+                "Retry-After",
+                (decision.retry_after.as_seconds_f64().ceil() as i64)
+                    .max(1)
+                    .to_string(),
+            ));
+        }
+    }
+}
+
+#[get("/rate-limited")]
+pub async fn rate_limited() -> models::ErrorResponse {
+    models::ErrorResponse::from(&Error::RateLimited(time::Duration::ZERO))
+}
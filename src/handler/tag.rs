@@ -0,0 +1,120 @@
+use crate::{
+    domain::{DeleteTagRequest, TagItemRequest, TagService},
+    handler::{
+        guards::{RequireScope, TodoRead, TodoWrite},
+        models,
+    },
+};
+use rocket::{State, http::Status, response::status::Custom, serde::json::Json};
+use std::sync::Arc;
+
+#[post("/tags", data = "<request>")]
+pub async fn post_tag(
+    auth_guard: RequireScope<TodoWrite>,
+    request: Json<models::CreateTagRequest>,
+    service: &State<Arc<dyn TagService>>,
+) -> Custom<Result<Json<models::Response<models::TagData>>, models::ErrorResponse>> {
+    let request = request.into_inner().into_domain(auth_guard.account_id);
+    match service.inner().create(request).await {
+        Ok(tag) => Custom(
+            Status::Created,
+            Ok(Json(models::Response::from(models::TagData::from(&tag)))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[get("/tags")]
+pub async fn get_tags(
+    auth_guard: RequireScope<TodoRead>,
+    service: &State<Arc<dyn TagService>>,
+) -> Custom<Result<Json<models::Response<Vec<models::TagData>>>, models::ErrorResponse>> {
+    match service.inner().list(auth_guard.account_id).await {
+        Ok(tags) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(
+                tags.iter().map(models::TagData::from).collect::<Vec<_>>(),
+            ))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[patch("/tags/<id>", data = "<request>")]
+pub async fn patch_tag(
+    auth_guard: RequireScope<TodoWrite>,
+    id: i32,
+    request: Json<models::UpdateTagRequest>,
+    service: &State<Arc<dyn TagService>>,
+) -> Custom<Result<Json<models::Response<models::TagData>>, models::ErrorResponse>> {
+    let request = request.into_inner().into_domain(id, auth_guard.account_id);
+    match service.inner().update(request).await {
+        Ok(tag) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(models::TagData::from(&tag)))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[delete("/tags/<id>")]
+pub async fn delete_tag(
+    auth_guard: RequireScope<TodoWrite>,
+    id: i32,
+    service: &State<Arc<dyn TagService>>,
+) -> Custom<Result<(), models::ErrorResponse>> {
+    let request = DeleteTagRequest {
+        owner_id: auth_guard.account_id,
+        tag_id: id,
+    };
+    match service.inner().delete(request).await {
+        Ok(()) => Custom(Status::NoContent, Ok(())),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[put("/todo/<id>/tags/<tag_id>")]
+pub async fn attach_tag(
+    auth_guard: RequireScope<TodoWrite>,
+    id: i32,
+    tag_id: i32,
+    service: &State<Arc<dyn TagService>>,
+) -> Custom<Result<Json<models::Response<models::TodoItemData>>, models::ErrorResponse>> {
+    let request = TagItemRequest {
+        owner_id: auth_guard.account_id,
+        item_id: id,
+        tag_id,
+    };
+    match service.inner().attach(request).await {
+        Ok(item) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(models::TodoItemData::from(
+                &item,
+            )))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[delete("/todo/<id>/tags/<tag_id>")]
+pub async fn detach_tag(
+    auth_guard: RequireScope<TodoWrite>,
+    id: i32,
+    tag_id: i32,
+    service: &State<Arc<dyn TagService>>,
+) -> Custom<Result<Json<models::Response<models::TodoItemData>>, models::ErrorResponse>> {
+    let request = TagItemRequest {
+        owner_id: auth_guard.account_id,
+        item_id: id,
+        tag_id,
+    };
+    match service.inner().detach(request).await {
+        Ok(item) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(models::TodoItemData::from(
+                &item,
+            )))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
-    domain::{TodoCreator, TodoGetter, TodoListerAndCounter, TodoUpdater},
+    domain::{
+        DeleteTodoItemRequest, GetTodoItemRequest, RestoreTodoItemRequest, TodoCreator,
+        TodoDeleter, TodoGetter, TodoListerAndCounter, TodoUpdater,
+    },
     handler::{
-        guards::AuthGuard,
+        guards::{RequireScope, TodoRead, TodoWrite, VerifiedAuthGuard},
         models::{self, UpdateTodoItemRequest},
     },
 };
@@ -10,10 +13,11 @@ use std::sync::Arc;
 
 #[post("/todo", data = "<request>")]
 pub async fn post_todo(
-    auth_guard: AuthGuard,
+    auth_guard: VerifiedAuthGuard,
+    _scope: RequireScope<TodoWrite>,
     request: Json<models::CreateTodoItemRequest>,
     creator: &State<Arc<dyn TodoCreator>>,
-) -> Custom<Result<Json<models::Response<models::TodoItemData>>, Json<models::ErrorResponse>>> {
+) -> Custom<Result<Json<models::Response<models::TodoItemData>>, models::ErrorResponse>> {
     let result = creator
         .inner()
         .create(request.into_inner().into_domain(auth_guard.account_id))
@@ -25,21 +29,18 @@ pub async fn post_todo(
                 &item,
             )))),
         ),
-        Err(err) => Custom(
-            Status::from(&err),
-            Err(Json(models::ErrorResponse::from(&err))),
-        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
     }
 }
 
 #[get("/todo?<filters..>")]
 pub async fn get_todo(
-    #[allow(dead_code, unused_variables)] auth_guard: AuthGuard,
+    auth_guard: RequireScope<TodoRead>,
     filters: models::GetTodoFilters,
     lister: &State<Arc<dyn TodoListerAndCounter>>,
-) -> Custom<Result<Json<models::Response<Vec<models::TodoItemData>>>, Json<models::ErrorResponse>>>
-{
-    match lister.inner().list(&filters.into()).await {
+) -> Custom<Result<Json<models::Response<Vec<models::TodoItemData>>>, models::ErrorResponse>> {
+    let filters = filters.into_domain(auth_guard.account_id);
+    match lister.inner().list(&filters).await {
         Ok((items, total)) => Custom(
             Status::Ok,
             Ok(Json(models::Response::from((
@@ -50,40 +51,40 @@ pub async fn get_todo(
                 total,
             )))),
         ),
-        Err(err) => Custom(
-            Status::from(&err),
-            Err(Json(models::ErrorResponse::from(&err))),
-        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
     }
 }
 
-#[get("/todo/<id>")]
+#[get("/todo/<id>?<trashed>")]
 pub async fn get_todo_by_id(
-    #[allow(dead_code, unused_variables)] auth_guard: AuthGuard,
+    auth_guard: RequireScope<TodoRead>,
     id: i32,
+    trashed: Option<bool>,
     getter: &State<Arc<dyn TodoGetter>>,
-) -> Custom<Result<Json<models::Response<models::TodoItemData>>, Json<models::ErrorResponse>>> {
-    match getter.inner().one(id).await {
+) -> Custom<Result<Json<models::Response<models::TodoItemData>>, models::ErrorResponse>> {
+    let request = GetTodoItemRequest {
+        owner_id: auth_guard.account_id,
+        item_id: id,
+        include_trashed: trashed.unwrap_or(false),
+    };
+    match getter.inner().one(request).await {
         Ok(item) => Custom(
             Status::Ok,
             Ok(Json(models::Response::from(models::TodoItemData::from(
                 &item,
             )))),
         ),
-        Err(err) => Custom(
-            Status::from(&err),
-            Err(Json(models::ErrorResponse::from(&err))),
-        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
     }
 }
 
 #[patch("/todo/<id>", data = "<request>")]
 pub async fn patch_todo_by_id(
-    auth_guard: AuthGuard,
+    auth_guard: RequireScope<TodoWrite>,
     id: i32,
     request: Json<UpdateTodoItemRequest>,
     updater: &State<Arc<dyn TodoUpdater>>,
-) -> Custom<Result<Json<models::Response<models::TodoItemData>>, Json<models::ErrorResponse>>> {
+) -> Custom<Result<Json<models::Response<models::TodoItemData>>, models::ErrorResponse>> {
     let request = request.into_inner().into_domain(id, auth_guard.account_id);
     let result = updater.inner().update(request).await;
     match result {
@@ -93,9 +94,48 @@ pub async fn patch_todo_by_id(
                 &item,
             )))),
         ),
-        Err(err) => Custom(
-            Status::from(&err),
-            Err(Json(models::ErrorResponse::from(&err))),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[delete("/todo/<id>")]
+pub async fn delete_todo_by_id(
+    auth_guard: RequireScope<TodoWrite>,
+    id: i32,
+    deleter: &State<Arc<dyn TodoDeleter>>,
+) -> Custom<Result<Json<models::Response<models::TodoItemData>>, models::ErrorResponse>> {
+    let request = DeleteTodoItemRequest {
+        owner_id: auth_guard.account_id,
+        item_id: id,
+    };
+    match deleter.inner().delete(request).await {
+        Ok(item) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(models::TodoItemData::from(
+                &item,
+            )))),
+        ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
+    }
+}
+
+#[post("/todo/<id>/restore")]
+pub async fn restore_todo_by_id(
+    auth_guard: RequireScope<TodoWrite>,
+    id: i32,
+    deleter: &State<Arc<dyn TodoDeleter>>,
+) -> Custom<Result<Json<models::Response<models::TodoItemData>>, models::ErrorResponse>> {
+    let request = RestoreTodoItemRequest {
+        owner_id: auth_guard.account_id,
+        item_id: id,
+    };
+    match deleter.inner().restore(request).await {
+        Ok(item) => Custom(
+            Status::Ok,
+            Ok(Json(models::Response::from(models::TodoItemData::from(
+                &item,
+            )))),
         ),
+        Err(err) => Custom(Status::from(&err), Err(models::ErrorResponse::from(&err))),
     }
 }
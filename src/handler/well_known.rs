@@ -0,0 +1,8 @@
+use crate::{domain::PublicKeyProvider, handler::models};
+use rocket::{State, serde::json::Json};
+use std::sync::Arc;
+
+#[get("/.well-known/jwks.json")]
+pub async fn get_jwks(provider: &State<Arc<dyn PublicKeyProvider>>) -> Json<models::JsonWebKeySet> {
+    Json(models::JsonWebKeySet::from(provider.inner().public_keys()))
+}
@@ -0,0 +1,4 @@
+mod outbox;
+mod smtp;
+pub use outbox::*;
+pub use smtp::*;
@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use crate::domain::{Error, Mail, Mailer};
+
+pub struct OutboxMailer {
+    directory: PathBuf,
+    from: String,
+}
+
+impl OutboxMailer {
+    pub fn new(directory: PathBuf, from: String) -> Self {
+        Self { directory, from }
+    }
+}
+
+#[async_trait]
+impl Mailer for OutboxMailer {
+    async fn send(&self, mail: Mail) -> Result<(), Error> {
+        let now = time::OffsetDateTime::now_utc();
+        let path = self
+            .directory
+            .join(format!("{}.eml", now.unix_timestamp_nanos()));
+        let content = format!(
+            "From: {}\r\nTo: {}\r\nSubject: {}\r\n\r\n{}\r\n",
+            self.from, mail.to, mail.subject, mail.body
+        );
+        rocket::tokio::fs::create_dir_all(&self.directory)
+            .await
+            .map_err(|err| Error::Unknown(err.to_string()))?;
+        rocket::tokio::fs::write(path, content)
+            .await
+            .map_err(|err| Error::Unknown(err.to_string()))
+    }
+}
@@ -0,0 +1,43 @@
+use crate::domain::{Error, Mail, Mailer};
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor, message::Mailbox,
+    message::header::ContentType,
+};
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(url: &str, from: &str) -> Result<Self, Error> {
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::from_url(url)
+            .map_err(|err| Error::Unknown(err.to_string()))?
+            .build();
+        let from = from
+            .parse::<Mailbox>()
+            .map_err(|err| Error::Unknown(err.to_string()))?;
+        Ok(Self { transport, from })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, mail: Mail) -> Result<(), Error> {
+        let to: Mailbox = match mail.to.parse() {
+            Ok(to) => to,
+            Err(err) => return Err(Error::OperationNotApplicable(err.to_string())),
+        };
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(mail.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(mail.body)
+            .map_err(|err| Error::Unknown(err.to_string()))?;
+        match self.transport.send(message).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
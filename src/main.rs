@@ -1,21 +1,75 @@
 mod auth;
 mod domain;
 mod handler;
+mod mail;
 mod repository;
 mod service;
 
+use rocket::fairing::AdHoc;
 use serde::Deserialize;
 use sqlx::postgres::PgPoolOptions;
-use std::sync::Arc;
+use std::{num::NonZeroU64, sync::Arc};
 
 #[macro_use]
 extern crate rocket;
 
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum MailTransport {
+    Smtp,
+    Outbox,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+enum RateLimitBackend {
+    #[default]
+    Memory,
+    Postgres,
+}
+
 #[derive(Deserialize)]
 struct Config {
     database_url: String,
     auth_token_duration_seconds: i64,
     auth_token_secret: String,
+    auth_token_keys: Option<String>,
+    auth_token_active_key: Option<String>,
+    refresh_token_duration_seconds: i64,
+    token_revocation_cache_ttl_seconds: i64,
+    session_touch_interval_seconds: NonZeroU64,
+    mail_transport: MailTransport,
+    mail_from: String,
+    smtp_url: Option<String>,
+    mail_outbox_dir: Option<String>,
+    password_reset_token_duration_seconds: i64,
+    password_reset_url: String,
+    email_verification_duration_seconds: i64,
+    email_verification_url: String,
+    #[serde(default)]
+    require_verified_email: bool,
+    login_attempt_window_seconds: i64,
+    login_max_failures_per_login: i64,
+    login_max_failures_per_ip: i64,
+    login_lockout_base_seconds: i64,
+    login_lockout_max_seconds: i64,
+    #[serde(default)]
+    trust_proxy_ip_header: bool,
+    password_min_length: usize,
+    password_max_length: usize,
+    password_banned_list_path: Option<String>,
+    password_hash_memory_kib: u32,
+    password_hash_iterations: u32,
+    password_hash_parallelism: u32,
+    totp_issuer: String,
+    mfa_token_duration_seconds: i64,
+    #[serde(default)]
+    rate_limit_store: RateLimitBackend,
+    rate_limit_capacity: u32,
+    rate_limit_period_seconds: i64,
+    rate_limit_routes: Option<String>,
+    todo_trash_retention_seconds: i64,
+    todo_trash_purge_interval_seconds: NonZeroU64,
 }
 
 #[launch]
@@ -35,20 +89,203 @@ async fn rocket() -> _ {
         .await
         .expect("Failed to run DB migrations.");
 
+    let access_token_repository =
+        repository::access_token::AccessTokenRepository::new(db_pool.clone());
     let account_repository = repository::account::AccountRepository::new(db_pool.clone());
+    let login_attempt_repository =
+        repository::login_attempt::LoginAttemptRepository::new(db_pool.clone());
+    let password_reset_token_repository =
+        repository::password_reset::PasswordResetTokenRepository::new(db_pool.clone());
+    let refresh_token_repository =
+        repository::refresh_token::RefreshTokenRepository::new(db_pool.clone());
+    let token_revocation_store = repository::revocation::CachedTokenRevocationStore::new(
+        repository::revocation::TokenRevocationRepository::new(db_pool.clone()),
+        time::Duration::seconds(config.token_revocation_cache_ttl_seconds),
+    );
+    let recovery_code_repository =
+        repository::recovery_code::RecoveryCodeRepository::new(db_pool.clone());
+    let session_repository = Arc::new(repository::session::BufferedSessionRepository::new(
+        repository::session::SessionRepository::new(db_pool.clone()),
+    ));
+    let session_touch_interval =
+        std::time::Duration::from_secs(config.session_touch_interval_seconds.get());
+    let rate_limit_rules = handler::rate_limit::RateLimiter::parse_rules(
+        config.rate_limit_routes.as_deref().unwrap_or_default(),
+    )
+    .expect("Failed to parse RATE_LIMIT_ROUTES.");
+    let rate_limit_retention = rate_limit_rules.iter().map(|rule| rule.period()).fold(
+        time::Duration::seconds(config.rate_limit_period_seconds),
+        time::Duration::max,
+    );
+    let rate_limit_store: Arc<dyn domain::RateLimitStore> = match config.rate_limit_store {
+        RateLimitBackend::Memory => Arc::new(repository::rate_limit::MemoryRateLimitStore::new(
+            rate_limit_retention,
+        )),
+        RateLimitBackend::Postgres => Arc::new(repository::rate_limit::RateLimitRepository::new(
+            db_pool.clone(),
+            rate_limit_retention,
+        )),
+    };
+    let project_repository = repository::project::ProjectRepository::new(db_pool.clone());
+    let tag_repository = repository::tag::TagRepository::new(db_pool.clone());
     let todo_repository = repository::todo::TodoRepository::new(db_pool);
 
-    let password_hasher = auth::PasswordHasher::new();
+    let password_hasher = auth::PasswordHasher::new(
+        config.password_hash_memory_kib,
+        config.password_hash_iterations,
+        config.password_hash_parallelism,
+    )
+    .expect("Invalid password hashing parameters.");
+    let password_policy = service::password_policy::PasswordPolicy::new(
+        config.password_min_length,
+        config.password_max_length,
+        config
+            .password_banned_list_path
+            .map(|path| {
+                std::fs::read_to_string(path).expect("Failed to read PASSWORD_BANNED_LIST_PATH.")
+            })
+            .as_deref(),
+    );
+    let secret_generator = auth::SecretGenerator::new();
+
+    let auth_token_keys = Arc::new(match config.auth_token_keys {
+        Some(keys) => auth::KeyRing::load(
+            &keys,
+            &config
+                .auth_token_active_key
+                .expect("AUTH_TOKEN_ACTIVE_KEY is required with AUTH_TOKEN_KEYS."),
+        )
+        .expect("Failed to load auth token keys."),
+        None => auth::KeyRing::hmac(&config.auth_token_secret),
+    });
 
     let auth_token_generator = auth::AuthTokenGenerator::new(
         time::Duration::seconds(config.auth_token_duration_seconds),
+        auth_token_keys.clone(),
+    );
+
+    let client_ip_config = handler::ClientIpConfig {
+        trust_proxy_header: config.trust_proxy_ip_header,
+    };
+    let rate_limiter = handler::rate_limit::RateLimiter::new(
+        rate_limit_store,
+        domain::RateLimit {
+            capacity: config.rate_limit_capacity,
+            period: time::Duration::seconds(config.rate_limit_period_seconds),
+        },
+        rate_limit_rules,
+        Arc::new(auth::AuthTokenGenerator::new(
+            time::Duration::seconds(config.auth_token_duration_seconds),
+            auth_token_keys.clone(),
+        )),
+        client_ip_config,
+    );
+
+    let email_verification_signer = auth::EmailVerificationSigner::new(
+        time::Duration::seconds(config.email_verification_duration_seconds),
+        config.auth_token_secret.clone(),
+    );
+
+    let mfa_token_signer = auth::MfaTokenSigner::new(
+        time::Duration::seconds(config.mfa_token_duration_seconds),
         config.auth_token_secret,
     );
 
+    let mailer: Arc<dyn domain::Mailer> = match config.mail_transport {
+        MailTransport::Smtp => Arc::new(
+            mail::SmtpMailer::new(
+                &config
+                    .smtp_url
+                    .expect("SMTP_URL is required for smtp transport."),
+                &config.mail_from,
+            )
+            .expect("Failed to init SMTP transport."),
+        ),
+        MailTransport::Outbox => Arc::new(mail::OutboxMailer::new(
+            config
+                .mail_outbox_dir
+                .expect("MAIL_OUTBOX_DIR is required for outbox transport.")
+                .into(),
+            config.mail_from,
+        )),
+    };
+
+    let session_service = Arc::new(service::session::SessionService::new(
+        auth_token_generator,
+        refresh_token_repository,
+        secret_generator,
+        token_revocation_store,
+        session_repository.clone(),
+        time::Duration::seconds(config.refresh_token_duration_seconds),
+    ));
+
+    let access_token_service = Arc::new(service::access_token::AccessTokenService::new(
+        access_token_repository,
+        auth::SecretGenerator::new(),
+    ));
+
+    let login_throttle = Arc::new(service::login_throttle::LoginThrottle::new(
+        login_attempt_repository,
+        service::login_throttle::LoginThrottleConfig {
+            window: time::Duration::seconds(config.login_attempt_window_seconds),
+            max_failures_per_login: config.login_max_failures_per_login,
+            max_failures_per_ip: config.login_max_failures_per_ip,
+            lockout_base: time::Duration::seconds(config.login_lockout_base_seconds),
+            lockout_max: time::Duration::seconds(config.login_lockout_max_seconds),
+        },
+    ));
+
+    let mfa_service = Arc::new(service::mfa::MfaService::new(
+        account_repository.clone(),
+        auth::TotpVerifier::new(config.totp_issuer),
+        recovery_code_repository,
+        mfa_token_signer,
+        auth::SecretGenerator::new(),
+        login_throttle.clone(),
+    ));
+
     let account_service = Arc::new(service::account::AccountService::new(
+        account_repository.clone(),
+        password_hasher.clone(),
+        session_service.clone(),
+        service::email_verification::EmailVerificationService::new(
+            mailer.clone(),
+            email_verification_signer,
+            service::email_verification::EmailVerificationConfig {
+                verification_url: config.email_verification_url,
+                required: config.require_verified_email,
+            },
+        ),
+        mfa_service.clone(),
+        login_throttle,
+        password_policy.clone(),
+    ));
+
+    let admin_service = Arc::new(service::admin::AdminService::new(
+        account_repository.clone(),
+        session_service.clone(),
+    ));
+
+    let personal_data_service = Arc::new(service::personal_data::PersonalDataService::new(
+        account_repository.clone(),
+        password_hasher.clone(),
+        todo_repository.clone(),
+        project_repository.clone(),
+        tag_repository.clone(),
+    ));
+
+    let password_reset_service = Arc::new(service::password_reset::PasswordResetService::new(
         account_repository,
         password_hasher,
-        auth_token_generator,
+        password_reset_token_repository,
+        auth::SecretGenerator::new(),
+        mailer,
+        session_service,
+        service::password_reset::PasswordResetConfig {
+            token_duration: time::Duration::seconds(config.password_reset_token_duration_seconds),
+            reset_url: config.password_reset_url,
+            password_policy,
+        },
     ));
 
     let todo_service = Arc::new(service::todo::TodoService::new(
@@ -56,24 +293,109 @@ async fn rocket() -> _ {
         todo_repository.clone(),
         todo_repository.clone(),
         todo_repository.clone(),
-        todo_repository,
+        todo_repository.clone(),
+        todo_repository.clone(),
+        project_repository.clone(),
+    ));
+
+    let project_service = Arc::new(service::project::ProjectService::new(project_repository));
+
+    let tag_service = Arc::new(service::tag::TagService::new(
+        tag_repository,
+        todo_repository.clone(),
     ));
 
+    let trash_purge_job = service::trash::TrashPurgeJob::new(
+        todo_repository,
+        time::Duration::seconds(config.todo_trash_retention_seconds),
+        std::time::Duration::from_secs(config.todo_trash_purge_interval_seconds.get()),
+    );
+
     rocket::build()
         .manage(account_service as Arc<dyn domain::AccountService>)
+        .manage(auth_token_keys as Arc<dyn domain::PublicKeyProvider>)
+        .manage(access_token_service as Arc<dyn domain::AccessTokenService>)
+        .manage(admin_service as Arc<dyn domain::AdminService>)
+        .manage(personal_data_service as Arc<dyn domain::PersonalDataService>)
+        .manage(mfa_service as Arc<dyn domain::MfaService>)
+        .manage(password_reset_service as Arc<dyn domain::PasswordResetService>)
         .manage(todo_service.clone() as Arc<dyn domain::TodoCreator>)
         .manage(todo_service.clone() as Arc<dyn domain::TodoListerAndCounter>)
         .manage(todo_service.clone() as Arc<dyn domain::TodoGetter>)
-        .manage(todo_service as Arc<dyn domain::TodoUpdater>)
+        .manage(todo_service.clone() as Arc<dyn domain::TodoUpdater>)
+        .manage(todo_service as Arc<dyn domain::TodoDeleter>)
+        .manage(project_service as Arc<dyn domain::ProjectService>)
+        .manage(tag_service as Arc<dyn domain::TagService>)
+        .manage(client_ip_config)
+        .attach(rate_limiter)
+        .attach(AdHoc::on_liftoff("Trash purge job", |_| {
+            Box::pin(async move {
+                rocket::tokio::spawn(trash_purge_job.run());
+            })
+        }))
+        .attach(AdHoc::on_liftoff("Session activity job", move |_| {
+            Box::pin(async move {
+                rocket::tokio::spawn(async move {
+                    session_repository.run(session_touch_interval).await;
+                });
+            })
+        }))
         .mount(
             "/",
             routes![
                 handler::register,
                 handler::login,
+                handler::login_mfa,
+                handler::refresh_token,
+                handler::logout,
+                handler::logout_all,
+                handler::change_password,
+                handler::export_account,
+                handler::delete_account,
+                handler::verify_email,
+                handler::resend_verification_email,
+                handler::forgot_password,
+                handler::reset_password,
+                handler::get_sessions,
+                handler::delete_session,
+                handler::enroll_totp,
+                handler::confirm_totp,
+                handler::post_access_token,
+                handler::get_access_tokens,
+                handler::delete_access_token,
                 handler::todo::post_todo,
                 handler::todo::get_todo,
                 handler::todo::get_todo_by_id,
-                handler::todo::patch_todo_by_id
+                handler::todo::patch_todo_by_id,
+                handler::todo::delete_todo_by_id,
+                handler::todo::restore_todo_by_id,
+                handler::project::post_project,
+                handler::project::get_projects,
+                handler::project::patch_project,
+                handler::project::archive_project,
+                handler::project::unarchive_project,
+                handler::project::delete_project,
+                handler::tag::post_tag,
+                handler::tag::get_tags,
+                handler::tag::patch_tag,
+                handler::tag::delete_tag,
+                handler::tag::attach_tag,
+                handler::tag::detach_tag,
+                handler::admin::get_accounts,
+                handler::admin::disable_account,
+                handler::admin::enable_account,
+                handler::admin::get_account_todo,
+                handler::well_known::get_jwks,
+                handler::rate_limit::rate_limited
+            ],
+        )
+        .register(
+            "/",
+            catchers![
+                handler::catchers::unauthorized,
+                handler::catchers::not_found,
+                handler::catchers::unprocessable_entity,
+                handler::catchers::default
             ],
         )
 }
@@ -0,0 +1,3 @@
+ALTER TABLE todo_items ADD COLUMN deleted_at TIMESTAMP NULL;
+
+CREATE INDEX todo_items_deleted_at_idx ON todo_items (deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,11 @@
+CREATE TABLE revoked_tokens (
+    jti VARCHAR(32) PRIMARY KEY,
+    account_id INTEGER NOT NULL REFERENCES accounts(id),
+    expires_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP DEFAULT NOW()
+);
+
+CREATE TABLE account_token_revocations (
+    account_id INTEGER PRIMARY KEY REFERENCES accounts(id),
+    revoked_before TIMESTAMP NOT NULL
+);
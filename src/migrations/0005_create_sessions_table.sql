@@ -0,0 +1,19 @@
+CREATE TABLE sessions (
+    id VARCHAR(36) PRIMARY KEY DEFAULT gen_random_uuid()::text,
+    account_id INTEGER NOT NULL REFERENCES accounts(id),
+    user_agent TEXT NULL,
+    ip_address VARCHAR(45) NULL,
+    created_at TIMESTAMP DEFAULT NOW(),
+    last_seen_at TIMESTAMP DEFAULT NOW(),
+    revoked_at TIMESTAMP NULL
+);
+
+CREATE INDEX sessions_account_id_idx ON sessions (account_id);
+
+INSERT INTO sessions (id, account_id, created_at, last_seen_at, revoked_at)
+SELECT family_id, account_id, MIN(created_at), MAX(created_at), MAX(revoked_at)
+FROM refresh_tokens
+GROUP BY family_id, account_id;
+
+ALTER TABLE refresh_tokens
+    ADD CONSTRAINT refresh_tokens_family_id_fkey FOREIGN KEY (family_id) REFERENCES sessions(id);
@@ -0,0 +1,2 @@
+ALTER TABLE accounts ADD COLUMN email VARCHAR(255) NULL;
+ALTER TABLE accounts ADD COLUMN email_verified_at TIMESTAMP NULL;
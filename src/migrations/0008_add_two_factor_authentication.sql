@@ -0,0 +1,12 @@
+ALTER TABLE accounts ADD COLUMN totp_secret VARCHAR(64) NULL;
+ALTER TABLE accounts ADD COLUMN totp_enabled_at TIMESTAMP NULL;
+
+CREATE TABLE recovery_codes (
+    id SERIAL PRIMARY KEY,
+    account_id INTEGER NOT NULL REFERENCES accounts(id),
+    code_hash VARCHAR(255) NOT NULL,
+    used_at TIMESTAMP NULL,
+    created_at TIMESTAMP DEFAULT NOW()
+);
+
+CREATE INDEX recovery_codes_account_id_idx ON recovery_codes (account_id);
@@ -0,0 +1,10 @@
+CREATE TABLE login_attempts (
+    id SERIAL PRIMARY KEY,
+    login VARCHAR(255) NOT NULL,
+    ip_address VARCHAR(45) NULL,
+    outcome VARCHAR(16) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX login_attempts_login_idx ON login_attempts (login, created_at);
+CREATE INDEX login_attempts_ip_address_idx ON login_attempts (ip_address, created_at);
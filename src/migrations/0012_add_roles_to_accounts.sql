@@ -0,0 +1,2 @@
+ALTER TABLE accounts ADD COLUMN role VARCHAR(16) NOT NULL DEFAULT 'user';
+ALTER TABLE accounts ADD COLUMN disabled_at TIMESTAMP NULL;
@@ -0,0 +1,10 @@
+UPDATE accounts SET login = LOWER(TRIM(login));
+
+UPDATE accounts
+SET login = accounts.login || '-' || accounts.id
+WHERE EXISTS (
+    SELECT 1 FROM accounts AS earlier
+    WHERE earlier.login = accounts.login AND earlier.id < accounts.id
+);
+
+CREATE UNIQUE INDEX accounts_login_key ON accounts (login);
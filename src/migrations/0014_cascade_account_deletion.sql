@@ -0,0 +1,42 @@
+ALTER TABLE todo_items
+    DROP CONSTRAINT todo_items_owner_id_fkey,
+    ADD CONSTRAINT todo_items_owner_id_fkey
+        FOREIGN KEY (owner_id) REFERENCES accounts(id) ON DELETE CASCADE;
+
+ALTER TABLE sessions
+    DROP CONSTRAINT sessions_account_id_fkey,
+    ADD CONSTRAINT sessions_account_id_fkey
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE;
+
+ALTER TABLE refresh_tokens
+    DROP CONSTRAINT refresh_tokens_account_id_fkey,
+    ADD CONSTRAINT refresh_tokens_account_id_fkey
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE,
+    DROP CONSTRAINT refresh_tokens_family_id_fkey,
+    ADD CONSTRAINT refresh_tokens_family_id_fkey
+        FOREIGN KEY (family_id) REFERENCES sessions(id) ON DELETE CASCADE;
+
+ALTER TABLE revoked_tokens
+    DROP CONSTRAINT revoked_tokens_account_id_fkey,
+    ADD CONSTRAINT revoked_tokens_account_id_fkey
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE;
+
+ALTER TABLE account_token_revocations
+    DROP CONSTRAINT account_token_revocations_account_id_fkey,
+    ADD CONSTRAINT account_token_revocations_account_id_fkey
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE;
+
+ALTER TABLE password_reset_tokens
+    DROP CONSTRAINT password_reset_tokens_account_id_fkey,
+    ADD CONSTRAINT password_reset_tokens_account_id_fkey
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE;
+
+ALTER TABLE recovery_codes
+    DROP CONSTRAINT recovery_codes_account_id_fkey,
+    ADD CONSTRAINT recovery_codes_account_id_fkey
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE;
+
+ALTER TABLE access_tokens
+    DROP CONSTRAINT access_tokens_account_id_fkey,
+    ADD CONSTRAINT access_tokens_account_id_fkey
+        FOREIGN KEY (account_id) REFERENCES accounts(id) ON DELETE CASCADE;
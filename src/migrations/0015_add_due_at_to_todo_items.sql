@@ -0,0 +1,3 @@
+ALTER TABLE todo_items ADD COLUMN due_at TIMESTAMPTZ NULL;
+
+CREATE INDEX todo_items_due_at_idx ON todo_items (owner_id, due_at) WHERE due_at IS NOT NULL;
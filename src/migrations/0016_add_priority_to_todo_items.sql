@@ -0,0 +1,3 @@
+ALTER TABLE todo_items ADD COLUMN priority SMALLINT NOT NULL DEFAULT 0;
+
+CREATE INDEX todo_items_priority_idx ON todo_items (owner_id, priority);
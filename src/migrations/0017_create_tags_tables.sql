@@ -0,0 +1,22 @@
+CREATE TABLE tags (
+    id SERIAL PRIMARY KEY,
+    owner_id INTEGER NOT NULL REFERENCES accounts(id) ON DELETE CASCADE,
+    name VARCHAR(50) NOT NULL,
+    color VARCHAR(7) NULL,
+    created_at TIMESTAMP DEFAULT NOW(),
+    updated_at TIMESTAMP DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX tags_owner_id_name_key ON tags (owner_id, LOWER(name));
+
+CREATE TRIGGER set_timestamp
+    BEFORE UPDATE ON tags FOR EACH ROW
+    EXECUTE PROCEDURE set_timestamp();
+
+CREATE TABLE todo_item_tags (
+    todo_item_id INTEGER NOT NULL REFERENCES todo_items(id) ON DELETE CASCADE,
+    tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    PRIMARY KEY (todo_item_id, tag_id)
+);
+
+CREATE INDEX todo_item_tags_tag_id_idx ON todo_item_tags (tag_id);
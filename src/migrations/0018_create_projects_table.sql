@@ -0,0 +1,19 @@
+CREATE TABLE projects (
+    id SERIAL PRIMARY KEY,
+    owner_id INTEGER NOT NULL REFERENCES accounts(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    archived_at TIMESTAMP NULL,
+    created_at TIMESTAMP DEFAULT NOW(),
+    updated_at TIMESTAMP DEFAULT NOW()
+);
+
+CREATE INDEX projects_owner_id_idx ON projects (owner_id);
+
+CREATE TRIGGER set_timestamp
+    BEFORE UPDATE ON projects FOR EACH ROW
+    EXECUTE PROCEDURE set_timestamp();
+
+ALTER TABLE todo_items
+    ADD COLUMN project_id INTEGER NULL REFERENCES projects(id) ON DELETE SET NULL;
+
+CREATE INDEX todo_items_project_id_idx ON todo_items (project_id) WHERE project_id IS NOT NULL;
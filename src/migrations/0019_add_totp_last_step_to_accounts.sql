@@ -0,0 +1 @@
+ALTER TABLE accounts ADD COLUMN totp_last_step BIGINT NULL;
@@ -0,0 +1 @@
+ALTER TABLE sessions ADD COLUMN scopes TEXT[] NOT NULL DEFAULT ARRAY['todo:read', 'todo:write'];
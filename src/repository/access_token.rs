@@ -0,0 +1,105 @@
+use sqlx::PgPool;
+
+use crate::{
+    domain::{
+        AccessToken, AccessTokenRepository as AccessTokenRepositoryTrait, CreateAccessTokenRequest,
+        Error, RevokeAccessTokenRequest,
+    },
+    repository::models,
+};
+
+#[derive(Clone)]
+pub struct AccessTokenRepository {
+    pool: PgPool,
+}
+
+impl AccessTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AccessTokenRepositoryTrait for AccessTokenRepository {
+    async fn create(&self, request: CreateAccessTokenRequest) -> Result<AccessToken, Error> {
+        let result = sqlx::query_as::<_, models::AccessToken>(
+            "INSERT INTO access_tokens (account_id, name, scopes, token_hash, expires_at) VALUES ($1, $2, $3, $4, NOW() + make_interval(secs => $5)) RETURNING *",
+        )
+        .bind(request.account_id)
+        .bind(request.name)
+        .bind(request.scopes)
+        .bind(request.token_hash)
+        .bind(request.duration.map(|duration| duration.as_seconds_f64()))
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(token) => {
+                AccessToken::try_from(token).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn list(&self, account_id: i32) -> Result<Vec<AccessToken>, Error> {
+        let result = sqlx::query_as::<_, models::AccessToken>(
+            "SELECT * FROM access_tokens WHERE account_id = $1 AND revoked_at IS NULL ORDER BY created_at DESC",
+        )
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await;
+        match result {
+            Ok(tokens) => tokens
+                .into_iter()
+                .map(|token| {
+                    AccessToken::try_from(token).map_err(|err| Error::Unknown(err.to_string()))
+                })
+                .collect(),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn get_by_hash(&self, token_hash: String) -> Result<AccessToken, Error> {
+        let result = sqlx::query_as::<_, models::AccessToken>(
+            "SELECT access_tokens.* FROM access_tokens JOIN accounts ON accounts.id = access_tokens.account_id WHERE access_tokens.token_hash = $1 AND access_tokens.revoked_at IS NULL AND accounts.disabled_at IS NULL",
+        )
+        .bind(token_hash)
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(token) => {
+                AccessToken::try_from(token).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }
+    }
+
+    async fn revoke(&self, request: RevokeAccessTokenRequest) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE access_tokens SET revoked_at = NOW() WHERE id = $1 AND account_id = $2 AND revoked_at IS NULL",
+        )
+        .bind(request.id)
+        .bind(request.account_id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(result) => Ok(result.rows_affected() == 1),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn touch(&self, id: i32) -> Result<(), Error> {
+        let result = sqlx::query(
+            "UPDATE access_tokens SET last_used_at = NOW() WHERE id = $1 AND (last_used_at IS NULL OR last_used_at < NOW() - INTERVAL '1 minute')",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
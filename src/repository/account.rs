@@ -1,7 +1,10 @@
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, QueryBuilder};
 
 use crate::{
-    domain::{Account, AccountRepository as AccountRepositoryTrait, CreateAccountRequest, Error},
+    domain::{
+        Account, AccountFilters, AccountRepository as AccountRepositoryTrait, CreateAccountRequest,
+        Error,
+    },
     repository::models,
 };
 
@@ -12,7 +15,7 @@ pub struct AccountRepository {
 
 impl AccountRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool: pool }
+        Self { pool }
     }
 }
 
@@ -20,14 +23,22 @@ impl AccountRepository {
 impl AccountRepositoryTrait for AccountRepository {
     async fn create(&self, request: CreateAccountRequest) -> Result<Account, Error> {
         let result = sqlx::query_as::<_, (i32,)>(
-            "INSERT INTO accounts (login, password) VALUES ($1, $2) RETURNING id",
+            "INSERT INTO accounts (login, password, email) VALUES ($1, $2, $3) RETURNING id",
         )
         .bind(request.login)
         .bind(request.password)
+        .bind(request.email)
         .fetch_one(&self.pool)
         .await;
         match result {
             Ok((id,)) => self.get(id).await,
+            Err(err)
+                if err
+                    .as_database_error()
+                    .is_some_and(|err| err.is_unique_violation()) =>
+            {
+                Err(Error::Conflict("login is already taken".to_string()))
+            }
             Err(err) => Err(Error::Unknown(err.to_string())),
         }
     }
@@ -38,7 +49,9 @@ impl AccountRepositoryTrait for AccountRepository {
             .fetch_one(&self.pool)
             .await;
         match result {
-            Ok(account) => Ok(account.into()),
+            Ok(account) => {
+                Account::try_from(account).map_err(|err| Error::Unknown(err.to_string()))
+            }
             Err(err) => match err {
                 sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
                 _ => Err(Error::Unknown(err.to_string())),
@@ -53,11 +66,165 @@ impl AccountRepositoryTrait for AccountRepository {
                 .fetch_one(&self.pool)
                 .await;
         match result {
-            Ok(account) => Ok(account.into()),
+            Ok(account) => {
+                Account::try_from(account).map_err(|err| Error::Unknown(err.to_string()))
+            }
             Err(err) => match err {
                 sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
                 _ => Err(Error::Unknown(err.to_string())),
             },
         }
     }
+
+    async fn update_password(&self, id: i32, password: String) -> Result<(), Error> {
+        let result = sqlx::query("UPDATE accounts SET password = $1 WHERE id = $2")
+            .bind(password)
+            .bind(id)
+            .execute(&self.pool)
+            .await;
+        match result {
+            Ok(result) if result.rows_affected() == 0 => {
+                Err(Error::NotFound(format!("account {} not found", id)))
+            }
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn verify_email(&self, id: i32, email: String) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE accounts SET email_verified_at = NOW() WHERE id = $1 AND email = $2 AND email_verified_at IS NULL",
+        )
+        .bind(id)
+        .bind(email)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(result) => Ok(result.rows_affected() == 1),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn set_totp_secret(&self, id: i32, secret: String) -> Result<(), Error> {
+        let result = sqlx::query(
+            "UPDATE accounts SET totp_secret = $1, totp_enabled_at = NULL, totp_last_step = NULL WHERE id = $2",
+        )
+        .bind(secret)
+        .bind(id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn enable_totp(&self, id: i32) -> Result<(), Error> {
+        let result = sqlx::query(
+            "UPDATE accounts SET totp_enabled_at = NOW() WHERE id = $1 AND totp_secret IS NOT NULL",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn accept_totp_step(&self, id: i32, step: u64) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE accounts SET totp_last_step = $1 WHERE id = $2 AND (totp_last_step IS NULL OR totp_last_step < $1)",
+        )
+        .bind(step as i64)
+        .bind(id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(result) => Ok(result.rows_affected() == 1),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn list(&self, filters: &AccountFilters) -> Result<Vec<Account>, Error> {
+        let mut query = QueryBuilder::<Postgres>::new("SELECT * FROM accounts ORDER BY id");
+
+        if let Some(limit) = filters.limit {
+            query.push(" LIMIT ");
+            query.push_bind(limit);
+        }
+
+        if let Some(offset) = filters.offset {
+            query.push(" OFFSET ");
+            query.push_bind(offset);
+        }
+
+        let result = query
+            .build_query_as::<models::Account>()
+            .fetch_all(&self.pool)
+            .await;
+        match result {
+            Ok(accounts) => accounts
+                .into_iter()
+                .map(|account| {
+                    Account::try_from(account).map_err(|err| Error::Unknown(err.to_string()))
+                })
+                .collect(),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn count(&self) -> Result<i64, Error> {
+        let result = sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM accounts")
+            .fetch_one(&self.pool)
+            .await;
+        match result {
+            Ok(count) => Ok(count.0),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn set_disabled(&self, id: i32, disabled: bool) -> Result<bool, Error> {
+        let query = if disabled {
+            "UPDATE accounts SET disabled_at = NOW() WHERE id = $1 AND disabled_at IS NULL"
+        } else {
+            "UPDATE accounts SET disabled_at = NULL WHERE id = $1 AND disabled_at IS NOT NULL"
+        };
+        let result = sqlx::query(query).bind(id).execute(&self.pool).await;
+        match result {
+            Ok(result) => Ok(result.rows_affected() == 1),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn delete(&self, id: i32) -> Result<bool, Error> {
+        let mut tx = match self.pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => return Err(Error::Unknown(err.to_string())),
+        };
+
+        let result =
+            sqlx::query_as::<_, (String,)>("DELETE FROM accounts WHERE id = $1 RETURNING login")
+                .bind(id)
+                .fetch_optional(&mut *tx)
+                .await;
+        let login = match result {
+            Ok(Some((login,))) => login,
+            Ok(None) => return Ok(false),
+            Err(err) => return Err(Error::Unknown(err.to_string())),
+        };
+
+        let result = sqlx::query("DELETE FROM login_attempts WHERE login = $1")
+            .bind(login)
+            .execute(&mut *tx)
+            .await;
+        if let Err(err) = result {
+            return Err(Error::Unknown(err.to_string()));
+        }
+
+        match tx.commit().await {
+            Ok(()) => Ok(true),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
 }
@@ -0,0 +1,78 @@
+use sqlx::PgPool;
+
+use crate::{
+    domain::{
+        Error, LoginAttemptRepository as LoginAttemptRepositoryTrait, LoginFailures,
+        RecordLoginAttemptRequest,
+    },
+    repository::models,
+};
+
+#[derive(Clone)]
+pub struct LoginAttemptRepository {
+    pool: PgPool,
+}
+
+impl LoginAttemptRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LoginAttemptRepositoryTrait for LoginAttemptRepository {
+    async fn record(&self, request: RecordLoginAttemptRequest) -> Result<(), Error> {
+        let result = sqlx::query(
+            "INSERT INTO login_attempts (login, ip_address, outcome) VALUES ($1, $2, $3)",
+        )
+        .bind(request.login)
+        .bind(request.ip_address)
+        .bind(request.outcome.to_string())
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn failures_by_login(
+        &self,
+        login: String,
+        window: time::Duration,
+    ) -> Result<LoginFailures, Error> {
+        let result = sqlx::query_as::<_, models::LoginFailures>(
+            "SELECT COUNT(*) AS count, MAX(created_at) AS last_failed_at FROM login_attempts WHERE login = $1 AND outcome = 'failure' AND created_at > NOW() - make_interval(secs => $2) AND created_at > COALESCE((SELECT MAX(created_at) FROM login_attempts WHERE login = $1 AND outcome = 'success'), '-infinity'::TIMESTAMP)",
+        )
+        .bind(login)
+        .bind(window.as_seconds_f64())
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(failures) => {
+                LoginFailures::try_from(failures).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn failures_by_ip(
+        &self,
+        ip_address: String,
+        window: time::Duration,
+    ) -> Result<LoginFailures, Error> {
+        let result = sqlx::query_as::<_, models::LoginFailures>(
+            "SELECT COUNT(*) AS count, MAX(created_at) AS last_failed_at FROM login_attempts WHERE ip_address = $1 AND outcome = 'failure' AND created_at > NOW() - make_interval(secs => $2)",
+        )
+        .bind(ip_address)
+        .bind(window.as_seconds_f64())
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(failures) => {
+                LoginFailures::try_from(failures).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
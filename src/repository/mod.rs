@@ -1,3 +1,13 @@
+pub mod access_token;
 pub mod account;
+pub mod login_attempt;
 pub mod models;
+pub mod password_reset;
+pub mod project;
+pub mod rate_limit;
+pub mod recovery_code;
+pub mod refresh_token;
+pub mod revocation;
+pub mod session;
+pub mod tag;
 pub mod todo;
@@ -1,9 +1,14 @@
 use std::str::FromStr;
 
 use crate::domain::{
-    Account as DomainAccount, Status as TodoItemStatus, TodoItem as DomainTodoItem,
+    AccessToken as DomainAccessToken, Account as DomainAccount,
+    LoginFailures as DomainLoginFailures, PasswordResetToken as DomainPasswordResetToken,
+    Priority as TodoItemPriority, Project as DomainProject, RecoveryCode as DomainRecoveryCode,
+    RefreshToken as DomainRefreshToken, Role as AccountRole, Session as DomainSession,
+    Status as TodoItemStatus, Tag as DomainTag, TodoItem as DomainTodoItem,
+    TokenBucket as DomainTokenBucket,
 };
-use sqlx::types::chrono::NaiveDateTime;
+use sqlx::types::chrono::{DateTime, NaiveDateTime, Utc};
 
 #[derive(sqlx::FromRow, sqlx::Decode)]
 pub struct Account {
@@ -11,51 +16,366 @@ pub struct Account {
     pub login: String,
     pub password: String,
     pub created_at: NaiveDateTime,
+    pub email: Option<String>,
+    pub email_verified_at: Option<NaiveDateTime>,
+    pub totp_secret: Option<String>,
+    pub totp_enabled_at: Option<NaiveDateTime>,
+    pub role: String,
+    pub disabled_at: Option<NaiveDateTime>,
 }
 
-impl Into<DomainAccount> for Account {
-    fn into(self) -> DomainAccount {
-        DomainAccount {
-            id: self.id,
-            login: self.login,
-            password: self.password,
-            created_at: time::OffsetDateTime::from_unix_timestamp(
-                self.created_at.and_utc().timestamp(),
-            )
-            .unwrap(),
-        }
+impl TryFrom<Account> for DomainAccount {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(account: Account) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: account.id,
+            login: account.login,
+            password: account.password,
+            email: account.email,
+            email_verified_at: account
+                .email_verified_at
+                .map(into_offset_date_time)
+                .transpose()?,
+            totp_secret: account.totp_secret,
+            totp_enabled_at: account
+                .totp_enabled_at
+                .map(into_offset_date_time)
+                .transpose()?,
+            role: AccountRole::from_str(&account.role)?,
+            disabled_at: account.disabled_at.map(into_offset_date_time).transpose()?,
+            created_at: into_offset_date_time(account.created_at)?,
+        })
     }
 }
 
 #[derive(sqlx::FromRow, sqlx::Decode)]
 pub struct TodoItem {
     pub id: i32,
-    pub owner_id: i32,
+    pub project_id: Option<i32>,
     pub title: String,
     pub status: String,
+    pub priority: i16,
     pub description: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub due_at: Option<DateTime<Utc>>,
 }
 
-impl TryInto<DomainTodoItem> for TodoItem {
+impl TryFrom<TodoItem> for DomainTodoItem {
     type Error = Box<dyn std::error::Error>;
 
-    fn try_into(self) -> Result<DomainTodoItem, Self::Error> {
-        let status = TodoItemStatus::from_str(&self.status)?;
+    fn try_from(item: TodoItem) -> Result<Self, Self::Error> {
+        let status = TodoItemStatus::from_str(&item.status)?;
+        let priority = TodoItemPriority::from_rank(item.priority)?;
         let created_at =
-            time::OffsetDateTime::from_unix_timestamp(self.created_at.and_utc().timestamp())?;
+            time::OffsetDateTime::from_unix_timestamp(item.created_at.and_utc().timestamp())?;
         let updated_at =
-            time::OffsetDateTime::from_unix_timestamp(self.updated_at.and_utc().timestamp())?;
+            time::OffsetDateTime::from_unix_timestamp(item.updated_at.and_utc().timestamp())?;
+        let deleted_at = match item.deleted_at {
+            Some(deleted_at) => Some(time::OffsetDateTime::from_unix_timestamp(
+                deleted_at.and_utc().timestamp(),
+            )?),
+            None => None,
+        };
+        let due_at = match item.due_at {
+            Some(due_at) => Some(time::OffsetDateTime::from_unix_timestamp(
+                due_at.timestamp(),
+            )?),
+            None => None,
+        };
+
+        Ok(Self {
+            id: item.id,
+            project_id: item.project_id,
+            title: item.title,
+            status,
+            priority,
+            description: item.description,
+            created_at,
+            updated_at,
+            deleted_at,
+            due_at,
+            tags: Vec::new(),
+        })
+    }
+}
+
+#[derive(sqlx::FromRow, sqlx::Decode)]
+pub struct Tag {
+    pub id: i32,
+    pub name: String,
+    pub color: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl TryFrom<Tag> for DomainTag {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(tag: Tag) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: tag.id,
+            name: tag.name,
+            color: tag.color,
+            created_at: into_offset_date_time(tag.created_at)?,
+            updated_at: into_offset_date_time(tag.updated_at)?,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow, sqlx::Decode)]
+pub struct Project {
+    pub id: i32,
+    pub name: String,
+    pub archived_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl TryFrom<Project> for DomainProject {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(project: Project) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: project.id,
+            name: project.name,
+            archived_at: project.archived_at.map(into_offset_date_time).transpose()?,
+            created_at: into_offset_date_time(project.created_at)?,
+            updated_at: into_offset_date_time(project.updated_at)?,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow)]
+pub struct TodoItemTag {
+    pub todo_item_id: i32,
+    #[sqlx(flatten)]
+    pub tag: Tag,
+}
+
+fn into_offset_date_time(
+    value: NaiveDateTime,
+) -> Result<time::OffsetDateTime, time::error::ComponentRange> {
+    time::OffsetDateTime::from_unix_timestamp(value.and_utc().timestamp())
+}
+
+#[derive(sqlx::FromRow, sqlx::Decode)]
+pub struct RefreshToken {
+    pub id: i32,
+    pub account_id: i32,
+    pub family_id: String,
+    pub expires_at: NaiveDateTime,
+    pub used_at: Option<NaiveDateTime>,
+    pub revoked_at: Option<NaiveDateTime>,
+}
+
+impl TryFrom<RefreshToken> for DomainRefreshToken {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(token: RefreshToken) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: token.id,
+            account_id: token.account_id,
+            family_id: token.family_id,
+            expires_at: into_offset_date_time(token.expires_at)?,
+            used_at: token.used_at.map(into_offset_date_time).transpose()?,
+            revoked_at: token.revoked_at.map(into_offset_date_time).transpose()?,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow, sqlx::Decode)]
+pub struct Session {
+    pub id: String,
+    pub account_id: i32,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub scopes: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub last_seen_at: NaiveDateTime,
+}
+
+impl TryFrom<Session> for DomainSession {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(session: Session) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: session.id,
+            account_id: session.account_id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            scopes: session.scopes,
+            created_at: into_offset_date_time(session.created_at)?,
+            last_seen_at: into_offset_date_time(session.last_seen_at)?,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow, sqlx::Decode)]
+pub struct PasswordResetToken {
+    pub id: i32,
+    pub account_id: i32,
+    pub expires_at: NaiveDateTime,
+    pub used_at: Option<NaiveDateTime>,
+}
+
+impl TryFrom<PasswordResetToken> for DomainPasswordResetToken {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(token: PasswordResetToken) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: token.id,
+            account_id: token.account_id,
+            expires_at: into_offset_date_time(token.expires_at)?,
+            used_at: token.used_at.map(into_offset_date_time).transpose()?,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow, sqlx::Decode)]
+pub struct RecoveryCode {
+    pub id: i32,
+    pub code_hash: String,
+}
+
+impl From<RecoveryCode> for DomainRecoveryCode {
+    fn from(code: RecoveryCode) -> Self {
+        Self {
+            id: code.id,
+            code_hash: code.code_hash,
+        }
+    }
+}
 
-        Ok(DomainTodoItem {
-            id: self.id,
-            owner_id: self.owner_id,
-            title: self.title,
-            status: status,
-            description: self.description,
-            created_at: created_at,
-            updated_at: updated_at,
+#[derive(sqlx::FromRow, sqlx::Decode)]
+pub struct AccessToken {
+    pub id: i32,
+    pub account_id: i32,
+    pub name: String,
+    pub scopes: Vec<String>,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub expires_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl TryFrom<AccessToken> for DomainAccessToken {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(token: AccessToken) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: token.id,
+            account_id: token.account_id,
+            name: token.name,
+            scopes: token.scopes,
+            last_used_at: token.last_used_at.map(into_offset_date_time).transpose()?,
+            expires_at: token.expires_at.map(into_offset_date_time).transpose()?,
+            created_at: into_offset_date_time(token.created_at)?,
         })
     }
 }
+
+#[derive(sqlx::FromRow, sqlx::Decode)]
+pub struct LoginFailures {
+    pub count: i64,
+    pub last_failed_at: Option<NaiveDateTime>,
+}
+
+impl TryFrom<LoginFailures> for DomainLoginFailures {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(failures: LoginFailures) -> Result<Self, Self::Error> {
+        Ok(Self {
+            count: failures.count,
+            last_failed_at: failures
+                .last_failed_at
+                .map(into_offset_date_time)
+                .transpose()?,
+        })
+    }
+}
+
+#[derive(sqlx::FromRow, sqlx::Decode)]
+pub struct RateLimitBucket {
+    pub tokens: f64,
+    pub updated_at: NaiveDateTime,
+}
+
+impl TryFrom<RateLimitBucket> for DomainTokenBucket {
+    type Error = Box<dyn std::error::Error>;
+
+    fn try_from(bucket: RateLimitBucket) -> Result<Self, Self::Error> {
+        let updated_at = bucket
+            .updated_at
+            .and_utc()
+            .timestamp_nanos_opt()
+            .ok_or("updated_at is out of range")?;
+        Ok(Self {
+            tokens: bucket.tokens,
+            updated_at: time::OffsetDateTime::from_unix_timestamp_nanos(updated_at as i128)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp() -> NaiveDateTime {
+        DateTime::from_timestamp(1_700_000_000, 0)
+            .unwrap()
+            .naive_utc()
+    }
+
+    fn account(role: &str) -> Account {
+        Account {
+            id: 1,
+            login: "alice".to_string(),
+            password: "hash".to_string(),
+            created_at: timestamp(),
+            email: None,
+            email_verified_at: None,
+            totp_secret: None,
+            totp_enabled_at: None,
+            role: role.to_string(),
+            disabled_at: None,
+        }
+    }
+
+    fn todo_item(status: &str, priority: i16) -> TodoItem {
+        TodoItem {
+            id: 1,
+            project_id: None,
+            title: "title".to_string(),
+            status: status.to_string(),
+            priority,
+            description: String::new(),
+            created_at: timestamp(),
+            updated_at: timestamp(),
+            deleted_at: None,
+            due_at: None,
+        }
+    }
+
+    #[test]
+    fn converts_valid_rows() {
+        assert!(DomainAccount::try_from(account("admin")).is_ok());
+        assert!(DomainTodoItem::try_from(todo_item("draft", 0)).is_ok());
+    }
+
+    #[test]
+    fn rejects_account_with_unknown_role() {
+        assert!(DomainAccount::try_from(account("superuser")).is_err());
+    }
+
+    #[test]
+    fn rejects_todo_item_with_unknown_status() {
+        assert!(DomainTodoItem::try_from(todo_item("archived", 0)).is_err());
+    }
+
+    #[test]
+    fn rejects_todo_item_with_unknown_priority() {
+        assert!(DomainTodoItem::try_from(todo_item("draft", 99)).is_err());
+    }
+}
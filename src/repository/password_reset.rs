@@ -0,0 +1,74 @@
+use sqlx::PgPool;
+
+use crate::{
+    domain::{
+        CreatePasswordResetTokenRequest, Error, PasswordResetToken,
+        PasswordResetTokenRepository as PasswordResetTokenRepositoryTrait,
+    },
+    repository::models,
+};
+
+#[derive(Clone)]
+pub struct PasswordResetTokenRepository {
+    pool: PgPool,
+}
+
+impl PasswordResetTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PasswordResetTokenRepositoryTrait for PasswordResetTokenRepository {
+    async fn create(
+        &self,
+        request: CreatePasswordResetTokenRequest,
+    ) -> Result<PasswordResetToken, Error> {
+        let result = sqlx::query_as::<_, models::PasswordResetToken>(
+            "INSERT INTO password_reset_tokens (account_id, token_hash, expires_at) VALUES ($1, $2, NOW() + make_interval(secs => $3)) RETURNING *",
+        )
+        .bind(request.account_id)
+        .bind(request.token_hash)
+        .bind(request.duration.as_seconds_f64())
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(token) => {
+                PasswordResetToken::try_from(token).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn get_by_hash(&self, token_hash: String) -> Result<PasswordResetToken, Error> {
+        let result = sqlx::query_as::<_, models::PasswordResetToken>(
+            "SELECT * FROM password_reset_tokens WHERE token_hash = $1",
+        )
+        .bind(token_hash)
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(token) => {
+                PasswordResetToken::try_from(token).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }
+    }
+
+    async fn mark_used(&self, id: i32) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE password_reset_tokens SET used_at = NOW() WHERE id = $1 AND used_at IS NULL",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(result) => Ok(result.rows_affected() == 1),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use sqlx::{PgPool, Postgres, QueryBuilder};
+
+use crate::{
+    domain::{
+        ArchiveProjectRequest, CreateProjectRequest, DeleteProjectMode, DeleteProjectRequest,
+        Error, Project, ProjectFilters, ProjectRepository as ProjectRepositoryTrait,
+        RenameProjectRequest,
+    },
+    repository::models,
+};
+
+#[derive(Clone)]
+pub struct ProjectRepository {
+    pool: PgPool,
+}
+
+impl ProjectRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ProjectRepositoryTrait for ProjectRepository {
+    async fn create(&self, request: CreateProjectRequest) -> Result<Project, Error> {
+        let result = sqlx::query_as::<_, models::Project>(
+            "INSERT INTO projects (owner_id, name) VALUES ($1, $2) RETURNING *",
+        )
+        .bind(request.owner_id)
+        .bind(request.name)
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(project) => {
+                Project::try_from(project).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn list(&self, filters: &ProjectFilters) -> Result<Vec<Project>, Error> {
+        let mut query = QueryBuilder::<Postgres>::new("SELECT * FROM projects WHERE owner_id = ");
+        query.push_bind(filters.owner_id);
+        if filters.archived {
+            query.push(" AND archived_at IS NOT NULL");
+        } else {
+            query.push(" AND archived_at IS NULL");
+        }
+        query.push(" ORDER BY LOWER(name), id");
+
+        let result = query
+            .build_query_as::<models::Project>()
+            .fetch_all(&self.pool)
+            .await;
+        match result {
+            Ok(projects) => projects
+                .into_iter()
+                .map(|project| {
+                    Project::try_from(project).map_err(|err| Error::Unknown(err.to_string()))
+                })
+                .collect(),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn get(&self, owner_id: i32, project_id: i32) -> Result<Project, Error> {
+        let result = sqlx::query_as::<_, models::Project>(
+            "SELECT * FROM projects WHERE id = $1 AND owner_id = $2",
+        )
+        .bind(project_id)
+        .bind(owner_id)
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(project) => {
+                Project::try_from(project).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }
+    }
+
+    async fn rename(&self, request: RenameProjectRequest) -> Result<Project, Error> {
+        let result = sqlx::query_as::<_, models::Project>(
+            "UPDATE projects SET name = $1 WHERE id = $2 AND owner_id = $3 RETURNING *",
+        )
+        .bind(request.name)
+        .bind(request.project_id)
+        .bind(request.owner_id)
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(project) => {
+                Project::try_from(project).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }
+    }
+
+    async fn set_archived(&self, request: ArchiveProjectRequest) -> Result<Project, Error> {
+        let query = if request.archived {
+            "UPDATE projects SET archived_at = NOW() WHERE id = $1 AND owner_id = $2 RETURNING *"
+        } else {
+            "UPDATE projects SET archived_at = NULL WHERE id = $1 AND owner_id = $2 RETURNING *"
+        };
+        let result = sqlx::query_as::<_, models::Project>(query)
+            .bind(request.project_id)
+            .bind(request.owner_id)
+            .fetch_one(&self.pool)
+            .await;
+        match result {
+            Ok(project) => {
+                Project::try_from(project).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }
+    }
+
+    async fn delete(&self, request: DeleteProjectRequest) -> Result<bool, Error> {
+        let mut tx = match self.pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => return Err(Error::Unknown(err.to_string())),
+        };
+
+        let target_id = match request.mode {
+            DeleteProjectMode::Move { target_id } => target_id,
+            DeleteProjectMode::Cascade => None,
+        };
+        let result = sqlx::query_as::<_, (i32, bool)>(
+            "SELECT id, archived_at IS NOT NULL FROM projects WHERE owner_id = $1 AND (id = $2 OR id = $3) ORDER BY id FOR UPDATE",
+        )
+        .bind(request.owner_id)
+        .bind(request.project_id)
+        .bind(target_id)
+        .fetch_all(&mut *tx)
+        .await;
+        let projects = match result {
+            Ok(projects) => projects,
+            Err(err) => return Err(Error::Unknown(err.to_string())),
+        };
+        if !projects.iter().any(|(id, _)| *id == request.project_id) {
+            return Ok(false);
+        }
+        if let Some(target_id) = target_id {
+            match projects.iter().find(|(id, _)| *id == target_id) {
+                None => return Err(Error::validation("target", "project not found")),
+                Some((_, true)) => return Err(Error::validation("target", "project is archived")),
+                Some(_) => {}
+            }
+        }
+
+        let result =
+            match request.mode {
+                DeleteProjectMode::Move { target_id } => sqlx::query(
+                    "UPDATE todo_items SET project_id = $1 WHERE project_id = $2 AND owner_id = $3",
+                )
+                .bind(target_id)
+                .bind(request.project_id)
+                .bind(request.owner_id)
+                .execute(&mut *tx)
+                .await,
+                DeleteProjectMode::Cascade => sqlx::query(
+                    "UPDATE todo_items SET deleted_at = COALESCE(deleted_at, NOW()), project_id = NULL WHERE project_id = $1 AND owner_id = $2",
+                )
+                .bind(request.project_id)
+                .bind(request.owner_id)
+                .execute(&mut *tx)
+                .await,
+            };
+        if let Err(err) = result {
+            return Err(Error::Unknown(err.to_string()));
+        }
+
+        let result = sqlx::query("DELETE FROM projects WHERE id = $1 AND owner_id = $2")
+            .bind(request.project_id)
+            .bind(request.owner_id)
+            .execute(&mut *tx)
+            .await;
+        if let Err(err) = result {
+            return Err(Error::Unknown(err.to_string()));
+        }
+
+        match tx.commit().await {
+            Ok(()) => Ok(true),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Mutex,
+        atomic::{AtomicI64, Ordering},
+    },
+};
+
+use sqlx::PgPool;
+
+use crate::{
+    domain::{Error, RateLimit, RateLimitDecision, RateLimitStore, TokenBucket},
+    repository::models,
+};
+
+struct MemoryBucket {
+    bucket: TokenBucket,
+    limit: RateLimit,
+}
+
+struct MemoryBuckets {
+    entries: HashMap<String, MemoryBucket>,
+    last_pruned_at: time::OffsetDateTime,
+}
+
+pub struct MemoryRateLimitStore {
+    retention: time::Duration,
+    buckets: Mutex<MemoryBuckets>,
+}
+
+impl MemoryRateLimitStore {
+    pub fn new(retention: time::Duration) -> Self {
+        Self {
+            retention,
+            buckets: Mutex::new(MemoryBuckets {
+                entries: HashMap::new(),
+                last_pruned_at: time::OffsetDateTime::now_utc(),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for MemoryRateLimitStore {
+    async fn take(&self, key: String, limit: &RateLimit) -> Result<RateLimitDecision, Error> {
+        let now = time::OffsetDateTime::now_utc();
+        let mut buckets = self.buckets.lock().unwrap();
+        if now - buckets.last_pruned_at >= self.retention {
+            buckets
+                .entries
+                .retain(|_, entry| !entry.bucket.is_full(&entry.limit, now));
+            buckets.last_pruned_at = now;
+        }
+        let entry = buckets.entries.entry(key).or_insert_with(|| MemoryBucket {
+            bucket: TokenBucket::full(limit, now),
+            limit: limit.clone(),
+        });
+        Ok(entry.bucket.take(limit, now))
+    }
+}
+
+pub struct RateLimitRepository {
+    pool: PgPool,
+    retention: time::Duration,
+    last_purged_at: AtomicI64,
+}
+
+impl RateLimitRepository {
+    pub fn new(pool: PgPool, retention: time::Duration) -> Self {
+        Self {
+            pool,
+            retention,
+            last_purged_at: AtomicI64::new(0),
+        }
+    }
+
+    async fn purge(&self, now: time::OffsetDateTime) -> Result<(), Error> {
+        let last_purged_at = self.last_purged_at.load(Ordering::Relaxed);
+        if now.unix_timestamp() - last_purged_at < self.retention.whole_seconds()
+            || self
+                .last_purged_at
+                .compare_exchange(
+                    last_purged_at,
+                    now.unix_timestamp(),
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                )
+                .is_err()
+        {
+            return Ok(());
+        }
+        let result = sqlx::query(
+            "DELETE FROM rate_limit_buckets WHERE updated_at < (NOW() AT TIME ZONE 'UTC') - make_interval(secs => $1)",
+        )
+        .bind(self.retention.as_seconds_f64())
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for RateLimitRepository {
+    async fn take(&self, key: String, limit: &RateLimit) -> Result<RateLimitDecision, Error> {
+        let now = time::OffsetDateTime::now_utc();
+        self.purge(now).await?;
+
+        let mut tx = match self.pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => return Err(Error::Unknown(err.to_string())),
+        };
+
+        let result = sqlx::query_as::<_, models::RateLimitBucket>(
+            "INSERT INTO rate_limit_buckets (key, tokens, updated_at) VALUES ($1, $2, NOW() AT TIME ZONE 'UTC') ON CONFLICT (key) DO UPDATE SET key = EXCLUDED.key RETURNING *",
+        )
+        .bind(&key)
+        .bind(limit.capacity as f64)
+        .fetch_one(&mut *tx)
+        .await;
+        let mut bucket = match result {
+            Ok(bucket) => {
+                TokenBucket::try_from(bucket).map_err(|err| Error::Unknown(err.to_string()))?
+            }
+            Err(err) => return Err(Error::Unknown(err.to_string())),
+        };
+
+        let decision = bucket.take(limit, now);
+        let result = sqlx::query(
+            "UPDATE rate_limit_buckets SET tokens = $2, updated_at = to_timestamp($3) AT TIME ZONE 'UTC' WHERE key = $1",
+        )
+        .bind(&key)
+        .bind(bucket.tokens)
+        .bind(now.unix_timestamp_nanos() as f64 / 1_000_000_000.0)
+        .execute(&mut *tx)
+        .await;
+        if let Err(err) = result {
+            return Err(Error::Unknown(err.to_string()));
+        }
+
+        match tx.commit().await {
+            Ok(()) => Ok(decision),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use sqlx::PgPool;
+
+use crate::{
+    domain::{Error, RecoveryCode, RecoveryCodeRepository as RecoveryCodeRepositoryTrait},
+    repository::models,
+};
+
+#[derive(Clone)]
+pub struct RecoveryCodeRepository {
+    pool: PgPool,
+}
+
+impl RecoveryCodeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RecoveryCodeRepositoryTrait for RecoveryCodeRepository {
+    async fn replace(&self, account_id: i32, code_hashes: Vec<String>) -> Result<(), Error> {
+        let mut tx = match self.pool.begin().await {
+            Ok(tx) => tx,
+            Err(err) => return Err(Error::Unknown(err.to_string())),
+        };
+
+        let result = sqlx::query("DELETE FROM recovery_codes WHERE account_id = $1")
+            .bind(account_id)
+            .execute(&mut *tx)
+            .await;
+        if let Err(err) = result {
+            return Err(Error::Unknown(err.to_string()));
+        }
+
+        let result = sqlx::query(
+            "INSERT INTO recovery_codes (account_id, code_hash) SELECT $1, UNNEST($2::VARCHAR[])",
+        )
+        .bind(account_id)
+        .bind(code_hashes)
+        .execute(&mut *tx)
+        .await;
+        if let Err(err) = result {
+            return Err(Error::Unknown(err.to_string()));
+        }
+
+        match tx.commit().await {
+            Ok(()) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn list_unused(&self, account_id: i32) -> Result<Vec<RecoveryCode>, Error> {
+        let result = sqlx::query_as::<_, models::RecoveryCode>(
+            "SELECT * FROM recovery_codes WHERE account_id = $1 AND used_at IS NULL",
+        )
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await;
+        match result {
+            Ok(codes) => Ok(codes.into_iter().map(RecoveryCode::from).collect()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn mark_used(&self, id: i32) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE recovery_codes SET used_at = NOW() WHERE id = $1 AND used_at IS NULL",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(result) => Ok(result.rows_affected() == 1),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use sqlx::PgPool;
+
+use crate::{
+    domain::{
+        CreateRefreshTokenRequest, Error, RefreshToken,
+        RefreshTokenRepository as RefreshTokenRepositoryTrait,
+    },
+    repository::models,
+};
+
+#[derive(Clone)]
+pub struct RefreshTokenRepository {
+    pool: PgPool,
+}
+
+impl RefreshTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepositoryTrait for RefreshTokenRepository {
+    async fn create(&self, request: CreateRefreshTokenRequest) -> Result<RefreshToken, Error> {
+        let result = sqlx::query_as::<_, models::RefreshToken>(
+            "INSERT INTO refresh_tokens (account_id, family_id, token_hash, expires_at) VALUES ($1, $2, $3, NOW() + make_interval(secs => $4)) RETURNING *",
+        )
+        .bind(request.account_id)
+        .bind(request.family_id)
+        .bind(request.token_hash)
+        .bind(request.duration.as_seconds_f64())
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(token) => {
+                RefreshToken::try_from(token).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn get_by_hash(&self, token_hash: String) -> Result<RefreshToken, Error> {
+        let result = sqlx::query_as::<_, models::RefreshToken>(
+            "SELECT * FROM refresh_tokens WHERE token_hash = $1",
+        )
+        .bind(token_hash)
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(token) => {
+                RefreshToken::try_from(token).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }
+    }
+
+    async fn mark_used(&self, id: i32) -> Result<bool, Error> {
+        let result = sqlx::query(
+            "UPDATE refresh_tokens SET used_at = NOW() WHERE id = $1 AND used_at IS NULL",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(result) => Ok(result.rows_affected() == 1),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn revoke_family(&self, family_id: String) -> Result<(), Error> {
+        let result = sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = NOW() WHERE family_id = $1 AND revoked_at IS NULL",
+        )
+        .bind(family_id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn revoke_account(&self, account_id: i32) -> Result<(), Error> {
+        let result = sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = NOW() WHERE account_id = $1 AND revoked_at IS NULL",
+        )
+        .bind(account_id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn revoke_other_families(&self, account_id: i32, family_id: String) -> Result<(), Error> {
+        let result = sqlx::query(
+            "UPDATE refresh_tokens SET revoked_at = NOW() WHERE account_id = $1 AND family_id <> $2 AND revoked_at IS NULL",
+        )
+        .bind(account_id)
+        .bind(family_id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
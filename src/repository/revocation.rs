@@ -0,0 +1,218 @@
+use std::{collections::HashMap, sync::RwLock};
+
+use sqlx::PgPool;
+
+use crate::domain::{AuthTokenClaims, Error, RevokeTokenRequest, TokenRevocationStore};
+
+#[derive(Clone)]
+pub struct TokenRevocationRepository {
+    pool: PgPool,
+}
+
+impl TokenRevocationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TokenRevocationStore for TokenRevocationRepository {
+    async fn revoke_token(&self, request: RevokeTokenRequest) -> Result<(), Error> {
+        let result = sqlx::query(
+            "INSERT INTO revoked_tokens (jti, account_id, expires_at) VALUES ($1, $2, to_timestamp($3) AT TIME ZONE 'UTC') ON CONFLICT (jti) DO NOTHING",
+        )
+        .bind(request.token_id)
+        .bind(request.account_id)
+        .bind(request.expires.unix_timestamp() as f64)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn revoke_session(&self, session_id: String) -> Result<(), Error> {
+        let result = sqlx::query(
+            "UPDATE sessions SET revoked_at = NOW() WHERE id = $1 AND revoked_at IS NULL",
+        )
+        .bind(session_id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn revoke_account(&self, account_id: i32) -> Result<(), Error> {
+        let result = sqlx::query(
+            "UPDATE sessions SET revoked_at = NOW() WHERE account_id = $1 AND revoked_at IS NULL",
+        )
+        .bind(account_id)
+        .execute(&self.pool)
+        .await;
+        if let Err(err) = result {
+            return Err(Error::Unknown(err.to_string()));
+        }
+
+        let result = sqlx::query(
+            "INSERT INTO account_token_revocations (account_id, revoked_before) VALUES ($1, date_trunc('second', NOW() AT TIME ZONE 'UTC')) ON CONFLICT (account_id) DO UPDATE SET revoked_before = EXCLUDED.revoked_before",
+        )
+        .bind(account_id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn revoke_other_sessions(
+        &self,
+        account_id: i32,
+        session_id: String,
+    ) -> Result<(), Error> {
+        let result = sqlx::query(
+            "UPDATE sessions SET revoked_at = NOW() WHERE account_id = $1 AND id <> $2 AND revoked_at IS NULL",
+        )
+        .bind(account_id)
+        .bind(session_id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn is_revoked(&self, claims: &AuthTokenClaims) -> Result<bool, Error> {
+        // Tokens of disabled accounts count as revoked, so the cache covers that check too.
+        let result = sqlx::query_as::<_, (bool,)>(
+            "SELECT EXISTS (SELECT 1 FROM revoked_tokens WHERE jti = $1) OR EXISTS (SELECT 1 FROM account_token_revocations WHERE account_id = $2 AND revoked_before > to_timestamp($3) AT TIME ZONE 'UTC') OR EXISTS (SELECT 1 FROM sessions WHERE id = $4 AND revoked_at IS NOT NULL) OR EXISTS (SELECT 1 FROM accounts WHERE id = $2 AND disabled_at IS NOT NULL)",
+        )
+        .bind(&claims.token_id)
+        .bind(claims.account_id)
+        .bind(claims.issued_at.unix_timestamp() as f64)
+        .bind(&claims.session_id)
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok((revoked,)) => Ok(revoked),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
+
+struct CacheEntry {
+    account_id: i32,
+    session_id: String,
+    revoked: bool,
+    valid_until: time::OffsetDateTime,
+}
+
+pub struct CachedTokenRevocationStore<S>
+where
+    S: TokenRevocationStore,
+{
+    store: S,
+    ttl: time::Duration,
+    entries: RwLock<HashMap<String, CacheEntry>>,
+}
+
+impl<S> CachedTokenRevocationStore<S>
+where
+    S: TokenRevocationStore,
+{
+    pub fn new(store: S, ttl: time::Duration) -> Self {
+        Self {
+            store,
+            ttl,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn cached(&self, token_id: &str) -> Option<bool> {
+        let entries = self.entries.read().unwrap();
+        entries
+            .get(token_id)
+            .filter(|entry| entry.valid_until > time::OffsetDateTime::now_utc())
+            .map(|entry| entry.revoked)
+    }
+
+    fn cache(&self, token_id: String, entry: CacheEntry) {
+        let now = time::OffsetDateTime::now_utc();
+        let mut entries = self.entries.write().unwrap();
+        entries.retain(|_, entry| entry.valid_until > now);
+        entries.insert(token_id, entry);
+    }
+}
+
+#[async_trait]
+impl<S> TokenRevocationStore for CachedTokenRevocationStore<S>
+where
+    S: TokenRevocationStore,
+{
+    async fn revoke_token(&self, request: RevokeTokenRequest) -> Result<(), Error> {
+        let token_id = request.token_id.clone();
+        let entry = CacheEntry {
+            account_id: request.account_id,
+            session_id: request.session_id.clone(),
+            revoked: true,
+            valid_until: request.expires,
+        };
+        self.store.revoke_token(request).await?;
+        self.cache(token_id, entry);
+        Ok(())
+    }
+
+    async fn revoke_session(&self, session_id: String) -> Result<(), Error> {
+        self.store.revoke_session(session_id.clone()).await?;
+        let mut entries = self.entries.write().unwrap();
+        entries.retain(|_, entry| entry.session_id != session_id);
+        Ok(())
+    }
+
+    async fn revoke_account(&self, account_id: i32) -> Result<(), Error> {
+        self.store.revoke_account(account_id).await?;
+        let mut entries = self.entries.write().unwrap();
+        entries.retain(|_, entry| entry.account_id != account_id);
+        Ok(())
+    }
+
+    async fn revoke_other_sessions(
+        &self,
+        account_id: i32,
+        session_id: String,
+    ) -> Result<(), Error> {
+        self.store
+            .revoke_other_sessions(account_id, session_id.clone())
+            .await?;
+        let mut entries = self.entries.write().unwrap();
+        entries.retain(|_, entry| entry.account_id != account_id || entry.session_id == session_id);
+        Ok(())
+    }
+
+    async fn is_revoked(&self, claims: &AuthTokenClaims) -> Result<bool, Error> {
+        if let Some(revoked) = self.cached(&claims.token_id) {
+            return Ok(revoked);
+        }
+
+        let revoked = self.store.is_revoked(claims).await?;
+        let valid_until = if revoked {
+            claims.expires
+        } else {
+            time::OffsetDateTime::now_utc() + self.ttl
+        };
+        self.cache(
+            claims.token_id.clone(),
+            CacheEntry {
+                account_id: claims.account_id,
+                session_id: claims.session_id.clone(),
+                revoked,
+                valid_until,
+            },
+        );
+        Ok(revoked)
+    }
+}
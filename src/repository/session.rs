@@ -0,0 +1,148 @@
+use std::{collections::HashSet, mem, sync::Mutex};
+
+use sqlx::PgPool;
+
+use crate::{
+    domain::{CreateSessionRequest, Error, Session, SessionRepository as SessionRepositoryTrait},
+    repository::models,
+};
+
+#[derive(Clone)]
+pub struct SessionRepository {
+    pool: PgPool,
+}
+
+impl SessionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SessionRepositoryTrait for SessionRepository {
+    async fn create(&self, request: CreateSessionRequest) -> Result<Session, Error> {
+        let result = sqlx::query_as::<_, models::Session>(
+            "INSERT INTO sessions (account_id, user_agent, ip_address, scopes) VALUES ($1, $2, $3, $4) RETURNING *",
+        )
+        .bind(request.account_id)
+        .bind(request.client.user_agent)
+        .bind(request.client.ip_address)
+        .bind(request.scopes)
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(session) => {
+                Session::try_from(session).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn get(&self, id: String) -> Result<Session, Error> {
+        let result = sqlx::query_as::<_, models::Session>(
+            "SELECT * FROM sessions WHERE id = $1 AND revoked_at IS NULL",
+        )
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(session) => {
+                Session::try_from(session).map_err(|err| Error::Unknown(err.to_string()))
+            }
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }
+    }
+
+    async fn list(&self, account_id: i32) -> Result<Vec<Session>, Error> {
+        let result = sqlx::query_as::<_, models::Session>(
+            "SELECT * FROM sessions WHERE account_id = $1 AND revoked_at IS NULL AND EXISTS (SELECT 1 FROM refresh_tokens WHERE family_id = sessions.id AND used_at IS NULL AND revoked_at IS NULL AND expires_at > NOW()) ORDER BY last_seen_at DESC",
+        )
+        .bind(account_id)
+        .fetch_all(&self.pool)
+        .await;
+        match result {
+            Ok(sessions) => sessions
+                .into_iter()
+                .map(|session| {
+                    Session::try_from(session).map_err(|err| Error::Unknown(err.to_string()))
+                })
+                .collect(),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn touch(&self, ids: Vec<String>) -> Result<(), Error> {
+        let result = sqlx::query("UPDATE sessions SET last_seen_at = NOW() WHERE id = ANY($1)")
+            .bind(ids)
+            .execute(&self.pool)
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
+
+pub struct BufferedSessionRepository<S>
+where
+    S: SessionRepositoryTrait,
+{
+    repository: S,
+    pending: Mutex<HashSet<String>>,
+}
+
+impl<S> BufferedSessionRepository<S>
+where
+    S: SessionRepositoryTrait,
+{
+    pub fn new(repository: S) -> Self {
+        Self {
+            repository,
+            pending: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub async fn flush(&self) -> Result<(), Error> {
+        let ids = mem::take(&mut *self.pending.lock().unwrap());
+        if ids.is_empty() {
+            return Ok(());
+        }
+        self.repository.touch(ids.into_iter().collect()).await
+    }
+
+    pub async fn run(&self, interval: std::time::Duration) {
+        let mut ticker = rocket::tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = self.flush().await {
+                error!("Failed to update session activity: {:?}", err);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<S> SessionRepositoryTrait for BufferedSessionRepository<S>
+where
+    S: SessionRepositoryTrait,
+{
+    async fn create(&self, request: CreateSessionRequest) -> Result<Session, Error> {
+        self.repository.create(request).await
+    }
+
+    async fn get(&self, id: String) -> Result<Session, Error> {
+        self.repository.get(id).await
+    }
+
+    async fn list(&self, account_id: i32) -> Result<Vec<Session>, Error> {
+        self.repository.list(account_id).await
+    }
+
+    async fn touch(&self, ids: Vec<String>) -> Result<(), Error> {
+        self.pending.lock().unwrap().extend(ids);
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use sqlx::{PgPool, Postgres, QueryBuilder};
+
+use crate::{
+    domain::{
+        CreateTagRequest, DeleteTagRequest, Error, Tag, TagRepository as TagRepositoryTrait,
+        UpdateTagRequest,
+    },
+    repository::models,
+};
+
+#[derive(Clone)]
+pub struct TagRepository {
+    pool: PgPool,
+}
+
+impl TagRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn map_write_error(err: sqlx::Error) -> Error {
+    match err {
+        sqlx::Error::RowNotFound => Error::NotFound(err.to_string()),
+        _ if err
+            .as_database_error()
+            .is_some_and(|err| err.is_unique_violation()) =>
+        {
+            Error::Conflict("tag with this name already exists".to_string())
+        }
+        _ => Error::Unknown(err.to_string()),
+    }
+}
+
+#[async_trait]
+impl TagRepositoryTrait for TagRepository {
+    async fn create(&self, request: CreateTagRequest) -> Result<Tag, Error> {
+        let result = sqlx::query_as::<_, models::Tag>(
+            "INSERT INTO tags (owner_id, name, color) VALUES ($1, $2, $3) RETURNING *",
+        )
+        .bind(request.owner_id)
+        .bind(request.name)
+        .bind(request.color)
+        .fetch_one(&self.pool)
+        .await;
+        match result {
+            Ok(tag) => Tag::try_from(tag).map_err(|err| Error::Unknown(err.to_string())),
+            Err(err) => Err(map_write_error(err)),
+        }
+    }
+
+    async fn list(&self, owner_id: i32) -> Result<Vec<Tag>, Error> {
+        let result = sqlx::query_as::<_, models::Tag>(
+            "SELECT * FROM tags WHERE owner_id = $1 ORDER BY LOWER(name)",
+        )
+        .bind(owner_id)
+        .fetch_all(&self.pool)
+        .await;
+        match result {
+            Ok(tags) => tags
+                .into_iter()
+                .map(|tag| Tag::try_from(tag).map_err(|err| Error::Unknown(err.to_string())))
+                .collect(),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn get(&self, owner_id: i32, tag_id: i32) -> Result<Tag, Error> {
+        let result =
+            sqlx::query_as::<_, models::Tag>("SELECT * FROM tags WHERE id = $1 AND owner_id = $2")
+                .bind(tag_id)
+                .bind(owner_id)
+                .fetch_one(&self.pool)
+                .await;
+        match result {
+            Ok(tag) => Tag::try_from(tag).map_err(|err| Error::Unknown(err.to_string())),
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }
+    }
+
+    async fn update(&self, request: UpdateTagRequest) -> Result<Tag, Error> {
+        if request.is_empty() {
+            return self.get(request.owner_id, request.tag_id).await;
+        }
+
+        let mut query = QueryBuilder::<Postgres>::new("UPDATE tags SET ");
+        let mut fields = query.separated(", ");
+
+        if let Some(name) = request.name {
+            fields.push("name = ");
+            fields.push_bind_unseparated(name);
+        }
+
+        if let Some(color) = request.color {
+            fields.push("color = ");
+            fields.push_bind_unseparated(color);
+        }
+
+        query.push(" WHERE id = ");
+        query.push_bind(request.tag_id);
+        query.push(" AND owner_id = ");
+        query.push_bind(request.owner_id);
+        query.push(" RETURNING *");
+
+        let result = query
+            .build_query_as::<models::Tag>()
+            .fetch_one(&self.pool)
+            .await;
+        match result {
+            Ok(tag) => Tag::try_from(tag).map_err(|err| Error::Unknown(err.to_string())),
+            Err(err) => Err(map_write_error(err)),
+        }
+    }
+
+    async fn delete(&self, request: DeleteTagRequest) -> Result<bool, Error> {
+        let result = sqlx::query("DELETE FROM tags WHERE id = $1 AND owner_id = $2")
+            .bind(request.tag_id)
+            .bind(request.owner_id)
+            .execute(&self.pool)
+            .await;
+        match result {
+            Ok(result) => Ok(result.rows_affected() == 1),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn attach(&self, item_id: i32, tag_id: i32) -> Result<(), Error> {
+        let result = sqlx::query(
+            "INSERT INTO todo_item_tags (todo_item_id, tag_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        )
+        .bind(item_id)
+        .bind(tag_id)
+        .execute(&self.pool)
+        .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+
+    async fn detach(&self, item_id: i32, tag_id: i32) -> Result<bool, Error> {
+        let result =
+            sqlx::query("DELETE FROM todo_item_tags WHERE todo_item_id = $1 AND tag_id = $2")
+                .bind(item_id)
+                .bind(tag_id)
+                .execute(&self.pool)
+                .await;
+        match result {
+            Ok(result) => Ok(result.rows_affected() == 1),
+            Err(err) => Err(Error::Unknown(err.to_string())),
+        }
+    }
+}
@@ -2,8 +2,10 @@ use sqlx::{PgPool, Postgres, QueryBuilder};
 
 use crate::{
     domain::{
-        CreateTodoItemRequest, Error, Filters, Status, TodoCounter, TodoCreator, TodoGetter,
-        TodoItem, TodoLister, TodoUpdater, UpdateTodoItemRequest,
+        CreateTodoItemRequest, DeleteTodoItemRequest, Error, Filters, GetTodoItemRequest,
+        ProjectFilter, RestoreTodoItemRequest, Sort, SortDirection, SortField, Status, Tag,
+        TagMatch, TodoCounter, TodoCreator, TodoDeleter, TodoGetter, TodoItem, TodoLister,
+        TodoPurger, TodoUpdater, UpdateTodoItemRequest,
     },
     repository::models,
 };
@@ -15,24 +17,152 @@ pub struct TodoRepository {
 
 impl TodoRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool: pool }
+        Self { pool }
+    }
+
+    async fn load_tags(&self, mut items: Vec<TodoItem>) -> Result<Vec<TodoItem>, Error> {
+        if items.is_empty() {
+            return Ok(items);
+        }
+
+        let result = sqlx::query_as::<_, models::TodoItemTag>(
+            "SELECT todo_item_tags.todo_item_id, tags.* FROM todo_item_tags JOIN tags ON tags.id = todo_item_tags.tag_id WHERE todo_item_tags.todo_item_id = ANY($1) ORDER BY LOWER(tags.name)",
+        )
+        .bind(items.iter().map(|item| item.id).collect::<Vec<_>>())
+        .fetch_all(&self.pool)
+        .await;
+        let records = match result {
+            Ok(records) => records,
+            Err(err) => return Err(Error::Unknown(err.to_string())),
+        };
+
+        for record in records {
+            let item_id = record.todo_item_id;
+            let tag = Tag::try_from(record.tag).map_err(|err| Error::Unknown(err.to_string()))?;
+            if let Some(item) = items.iter_mut().find(|item| item.id == item_id) {
+                item.tags.push(tag);
+            }
+        }
+        Ok(items)
+    }
+
+    async fn with_tags(&self, item: TodoItem) -> Result<TodoItem, Error> {
+        let mut items = self.load_tags(vec![item]).await?;
+        Ok(items.remove(0))
     }
 }
 
+fn push_filters(query: &mut QueryBuilder<'_, Postgres>, filters: &Filters) {
+    query.push(" WHERE owner_id = ");
+    query.push_bind(filters.owner_id);
+
+    if filters.trashed {
+        query.push(" AND deleted_at IS NOT NULL");
+    } else {
+        query.push(" AND deleted_at IS NULL");
+    }
+
+    if let Some(status) = &filters.status {
+        query.push(" AND status = ");
+        query.push_bind(status.to_string());
+    }
+
+    match filters.project {
+        Some(ProjectFilter::Unassigned) => {
+            query.push(" AND project_id IS NULL");
+        }
+        Some(ProjectFilter::Project(project_id)) => {
+            query.push(" AND project_id = ");
+            query.push_bind(project_id);
+        }
+        None => {}
+    }
+
+    if let Some(due_before) = filters.due_before {
+        query.push(" AND due_at < to_timestamp(");
+        query.push_bind(due_before.unix_timestamp() as f64);
+        query.push(")");
+    }
+
+    if let Some(due_after) = filters.due_after {
+        query.push(" AND due_at >= to_timestamp(");
+        query.push_bind(due_after.unix_timestamp() as f64);
+        query.push(")");
+    }
+
+    if !filters.tags.is_empty() {
+        let mut names: Vec<String> = filters.tags.iter().map(|tag| tag.to_lowercase()).collect();
+        names.sort();
+        names.dedup();
+        query.push(" AND id IN (SELECT todo_item_tags.todo_item_id FROM todo_item_tags JOIN tags ON tags.id = todo_item_tags.tag_id WHERE tags.owner_id = ");
+        query.push_bind(filters.owner_id);
+        query.push(" AND LOWER(tags.name) = ANY(");
+        let count = names.len() as i64;
+        query.push_bind(names);
+        query.push(")");
+        if filters.tag_match == TagMatch::All {
+            query.push(" GROUP BY todo_item_tags.todo_item_id HAVING COUNT(*) = ");
+            query.push_bind(count);
+        }
+        query.push(")");
+    }
+
+    if filters.overdue {
+        query.push(" AND due_at < NOW() AND status NOT IN (");
+        query.push_bind(Status::Completed.to_string());
+        query.push(", ");
+        query.push_bind(Status::Rejected.to_string());
+        query.push(")");
+    }
+}
+
+fn push_order_by(query: &mut QueryBuilder<'_, Postgres>, sort: &[Sort]) {
+    query.push(" ORDER BY ");
+    for key in sort {
+        query.push(match key.field {
+            SortField::Priority => "priority",
+            SortField::DueAt => "due_at",
+            SortField::CreatedAt => "created_at",
+            SortField::UpdatedAt => "updated_at",
+            SortField::Title => "title",
+        });
+        query.push(match key.direction {
+            SortDirection::Asc => " ASC",
+            SortDirection::Desc => " DESC",
+        });
+        if key.field == SortField::DueAt {
+            query.push(" NULLS LAST");
+        }
+        query.push(", ");
+    }
+    query.push("id ASC");
+}
+
 #[async_trait]
 impl TodoCreator for TodoRepository {
     async fn create(&self, request: CreateTodoItemRequest) -> Result<TodoItem, Error> {
+        let owner_id = request.owner_id;
         let result = sqlx::query_as::<_, (i32,)>(
-            "INSERT INTO todo_items (owner_id, title, status, description) VALUES ($1, $2, $3, $4) RETURNING id",
+            "INSERT INTO todo_items (owner_id, title, status, description, priority, due_at, project_id) VALUES ($1, $2, $3, $4, $5, to_timestamp($6), $7) RETURNING id",
         )
         .bind(request.owner_id)
         .bind(request.title)
         .bind(Status::Draft.to_string())
         .bind(request.description)
+        .bind(request.priority.rank())
+        .bind(request.due_at.map(|due_at| due_at.unix_timestamp() as f64))
+        .bind(request.project_id)
         .fetch_one(&self.pool)
         .await;
         match result {
-            Ok(row) => self.one(row.0).await,
+            Ok(row) => {
+                self.one(GetTodoItemRequest {
+                    owner_id,
+                    item_id: row.0,
+                    include_trashed: false,
+                })
+                .await
+            }
             Err(err) => Err(Error::Unknown(err.to_string())),
         }
     }
@@ -43,10 +173,7 @@ impl TodoCounter for TodoRepository {
     async fn count(&self, filters: &Filters) -> Result<i64, Error> {
         let mut query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM todo_items");
 
-        if let Some(status) = &filters.status {
-            query.push(" WHERE status = ");
-            query.push_bind(status.to_string());
-        }
+        push_filters(&mut query, filters);
 
         let result = query.build_query_as::<(i64,)>().fetch_one(&self.pool).await;
 
@@ -62,10 +189,8 @@ impl TodoLister for TodoRepository {
     async fn list(&self, filters: &Filters) -> Result<Vec<TodoItem>, Error> {
         let mut query = QueryBuilder::<Postgres>::new("SELECT * FROM todo_items");
 
-        if let Some(status) = &filters.status {
-            query.push(" WHERE status = ");
-            query.push_bind(status.to_string());
-        }
+        push_filters(&mut query, filters);
+        push_order_by(&mut query, &filters.sort);
 
         if let Some(limit) = filters.limit {
             query.push(" LIMIT ");
@@ -82,43 +207,159 @@ impl TodoLister for TodoRepository {
             .fetch_all(&self.pool)
             .await;
 
-        match result {
-            Ok(records) => Ok(records.into_iter().map(|x| x.try_into().unwrap()).collect()),
+        let items = match result {
+            Ok(records) => records
+                .into_iter()
+                .map(|record| {
+                    TodoItem::try_from(record).map_err(|err| Error::Unknown(err.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>(),
             Err(err) => Err(Error::Unknown(err.to_string())),
-        }
+        }?;
+        self.load_tags(items).await
     }
 }
 
 #[async_trait]
 impl TodoGetter for TodoRepository {
-    async fn one(&self, id: i32) -> Result<TodoItem, Error> {
-        let result =
-            sqlx::query_as::<_, models::TodoItem>("SELECT * FROM todo_items WHERE id = $1")
-                .bind(id)
-                .fetch_one(&self.pool)
-                .await;
-        match result {
-            Ok(record) => Ok(record.try_into().unwrap()),
+    async fn one(&self, request: GetTodoItemRequest) -> Result<TodoItem, Error> {
+        let result = sqlx::query_as::<_, models::TodoItem>(
+            "SELECT * FROM todo_items WHERE id = $1 AND owner_id = $2 AND ($3 OR deleted_at IS NULL)",
+        )
+        .bind(request.item_id)
+        .bind(request.owner_id)
+        .bind(request.include_trashed)
+        .fetch_one(&self.pool)
+        .await;
+        let item = match result {
+            Ok(record) => TodoItem::try_from(record).map_err(|err| Error::Unknown(err.to_string())),
             Err(err) => match err {
                 sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
                 _ => Err(Error::Unknown(err.to_string())),
             },
-        }
+        }?;
+        self.with_tags(item).await
     }
 }
 
 #[async_trait]
 impl TodoUpdater for TodoRepository {
     async fn update(&self, request: UpdateTodoItemRequest) -> Result<TodoItem, Error> {
+        if request.is_empty() {
+            return self
+                .one(GetTodoItemRequest {
+                    owner_id: request.owner_id,
+                    item_id: request.item_id,
+                    include_trashed: false,
+                })
+                .await;
+        }
+
+        let mut query = QueryBuilder::<Postgres>::new("UPDATE todo_items SET ");
+        let mut fields = query.separated(", ");
+
+        if let Some(title) = request.title {
+            fields.push("title = ");
+            fields.push_bind_unseparated(title);
+        }
+
+        if let Some(description) = request.description {
+            fields.push("description = ");
+            fields.push_bind_unseparated(description);
+        }
+
+        if let Some(status) = request.status {
+            fields.push("status = ");
+            fields.push_bind_unseparated(status.to_string());
+        }
+
+        if let Some(priority) = request.priority {
+            fields.push("priority = ");
+            fields.push_bind_unseparated(priority.rank());
+        }
+
+        if let Some(due_at) = request.due_at {
+            fields.push("due_at = to_timestamp(");
+            fields.push_bind_unseparated(due_at.map(|due_at| due_at.unix_timestamp() as f64));
+            fields.push_unseparated(")");
+        }
+
+        if let Some(project_id) = request.project_id {
+            fields.push("project_id = ");
+            fields.push_bind_unseparated(project_id);
+        }
+
+        query.push(" WHERE id = ");
+        query.push_bind(request.item_id);
+        query.push(" AND owner_id = ");
+        query.push_bind(request.owner_id);
+        query.push(" RETURNING *");
+
+        let result = query
+            .build_query_as::<models::TodoItem>()
+            .fetch_one(&self.pool)
+            .await;
+        let item = match result {
+            Ok(item) => TodoItem::try_from(item).map_err(|err| Error::Unknown(err.to_string())),
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }?;
+        self.with_tags(item).await
+    }
+}
+
+#[async_trait]
+impl TodoDeleter for TodoRepository {
+    async fn delete(&self, request: DeleteTodoItemRequest) -> Result<TodoItem, Error> {
+        let result = sqlx::query_as::<_, models::TodoItem>(
+            "UPDATE todo_items SET deleted_at = NOW() WHERE id = $1 AND owner_id = $2 AND deleted_at IS NULL RETURNING *",
+        )
+        .bind(request.item_id)
+        .bind(request.owner_id)
+        .fetch_one(&self.pool)
+        .await;
+        let item = match result {
+            Ok(item) => TodoItem::try_from(item).map_err(|err| Error::Unknown(err.to_string())),
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }?;
+        self.with_tags(item).await
+    }
+
+    async fn restore(&self, request: RestoreTodoItemRequest) -> Result<TodoItem, Error> {
         let result = sqlx::query_as::<_, models::TodoItem>(
-            "UPDATE todo_items SET status = $1 WHERE id = $2 RETURNING *",
+            "UPDATE todo_items SET deleted_at = NULL WHERE id = $1 AND owner_id = $2 AND deleted_at IS NOT NULL RETURNING *",
         )
-        .bind(request.status.to_string())
         .bind(request.item_id)
+        .bind(request.owner_id)
         .fetch_one(&self.pool)
         .await;
+        let item = match result {
+            Ok(item) => TodoItem::try_from(item).map_err(|err| Error::Unknown(err.to_string())),
+            Err(err) => match err {
+                sqlx::Error::RowNotFound => Err(Error::NotFound(err.to_string())),
+                _ => Err(Error::Unknown(err.to_string())),
+            },
+        }?;
+        self.with_tags(item).await
+    }
+}
+
+#[async_trait]
+impl TodoPurger for TodoRepository {
+    async fn purge(&self, retention: time::Duration) -> Result<u64, Error> {
+        let result = sqlx::query(
+            "DELETE FROM todo_items WHERE deleted_at < NOW() - make_interval(secs => $1)",
+        )
+        .bind(retention.as_seconds_f64())
+        .execute(&self.pool)
+        .await;
         match result {
-            Ok(item) => Ok(item.try_into().unwrap()),
+            Ok(result) => Ok(result.rows_affected()),
             Err(err) => Err(Error::Unknown(err.to_string())),
         }
     }
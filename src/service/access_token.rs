@@ -0,0 +1,113 @@
+use crate::domain::{
+    ACCESS_TOKEN_PREFIX, AccessToken, AccessTokenRepository,
+    AccessTokenService as AccessTokenServiceTrait, CreateAccessTokenRequest, Error,
+    IssueAccessTokenRequest, IssuedAccessToken, RevokeAccessTokenRequest, SecretGenerator,
+    validate_scopes,
+};
+
+const NAME_MAX_LENGTH: usize = 100;
+
+fn validate_name(name: &str) -> Result<(), Error> {
+    if name.trim().is_empty() {
+        return Err(Error::validation("name", "must not be empty"));
+    }
+    if name.chars().count() > NAME_MAX_LENGTH {
+        return Err(Error::validation(
+            "name",
+            format!("must be at most {} characters long", NAME_MAX_LENGTH),
+        ));
+    }
+    Ok(())
+}
+
+pub struct AccessTokenService<R, S>
+where
+    R: AccessTokenRepository,
+    S: SecretGenerator,
+{
+    repository: R,
+    secret_generator: S,
+}
+
+impl<R, S> AccessTokenService<R, S>
+where
+    R: AccessTokenRepository,
+    S: SecretGenerator,
+{
+    pub fn new(repository: R, secret_generator: S) -> Self {
+        Self {
+            repository,
+            secret_generator,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, S> AccessTokenServiceTrait for AccessTokenService<R, S>
+where
+    R: AccessTokenRepository,
+    S: SecretGenerator,
+{
+    async fn issue(&self, request: IssueAccessTokenRequest) -> Result<IssuedAccessToken, Error> {
+        validate_name(&request.name)?;
+        let mut scopes = request.scopes;
+        scopes.sort();
+        scopes.dedup();
+        validate_scopes(&scopes)?;
+        if request
+            .duration
+            .is_some_and(|duration| !duration.is_positive())
+        {
+            return Err(Error::OperationNotApplicable(
+                "expiration must be in the future".to_string(),
+            ));
+        }
+
+        let secret = format!(
+            "{}{}",
+            ACCESS_TOKEN_PREFIX,
+            self.secret_generator.generate().value
+        );
+        let token = self
+            .repository
+            .create(CreateAccessTokenRequest {
+                account_id: request.account_id,
+                name: request.name.trim().to_string(),
+                scopes,
+                token_hash: self.secret_generator.hash(&secret),
+                duration: request.duration,
+            })
+            .await?;
+        Ok(IssuedAccessToken { token, secret })
+    }
+
+    async fn list(&self, account_id: i32) -> Result<Vec<AccessToken>, Error> {
+        self.repository.list(account_id).await
+    }
+
+    async fn revoke(&self, request: RevokeAccessTokenRequest) -> Result<(), Error> {
+        if !self.repository.revoke(request).await? {
+            return Err(Error::NotFound("access token not found".to_string()));
+        }
+        Ok(())
+    }
+
+    async fn authorize(&self, token: String) -> Result<AccessToken, Error> {
+        let token_hash = self.secret_generator.hash(&token);
+        let stored = match self.repository.get_by_hash(token_hash).await {
+            Ok(stored) => stored,
+            Err(Error::NotFound(_)) => {
+                return Err(Error::Unauthorized("unknown access token".to_string()));
+            }
+            Err(err) => return Err(err),
+        };
+        if stored
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= time::OffsetDateTime::now_utc())
+        {
+            return Err(Error::Unauthorized("access token is expired".to_string()));
+        }
+        self.repository.touch(stored.id).await?;
+        Ok(stored)
+    }
+}
@@ -1,63 +1,309 @@
-use crate::domain::{
-    AccountRepository, AccountService as AccountServiceTrait, AuthToken, AuthTokenGenerator,
-    CreateAccountRequest, Error, LoginRequest, PasswordHasher, RegisterRequest,
+use crate::{
+    domain::{
+        AccountRepository, AccountService as AccountServiceTrait, AuthTokenClaims,
+        ChangePasswordRequest, CreateAccountRequest, CreateSessionRequest, DeleteSessionRequest,
+        EmailVerifier, Error, LoginRequest, LoginResult, LoginThrottle, MfaLoginRequest,
+        MfaService, PasswordHasher, RefreshRequest, RegisterRequest, SCOPES, Session,
+        SessionService, TokenPair, VerifyEmailRequest, normalize_login, validate_scopes,
+    },
+    service::password_policy::PasswordPolicy,
 };
 
-pub struct AccountService<R, H, G>
+const LOGIN_MIN_LENGTH: usize = 3;
+const LOGIN_MAX_LENGTH: usize = 32;
+
+fn validate_login(login: &str) -> Result<(), Error> {
+    let length = login.chars().count();
+    if !(LOGIN_MIN_LENGTH..=LOGIN_MAX_LENGTH).contains(&length) {
+        return Err(Error::validation(
+            "login",
+            format!(
+                "must be between {} and {} characters long",
+                LOGIN_MIN_LENGTH, LOGIN_MAX_LENGTH
+            ),
+        ));
+    }
+    if !login
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-'))
+    {
+        return Err(Error::validation(
+            "login",
+            "may only contain letters, digits, '.', '_' and '-'",
+        ));
+    }
+    Ok(())
+}
+
+fn validate_email(email: &str) -> Result<(), Error> {
+    let valid = match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.contains('@')
+                && !email.chars().any(char::is_whitespace)
+        }
+        None => false,
+    };
+    if !valid {
+        return Err(Error::validation("email", "is not a valid email address"));
+    }
+    Ok(())
+}
+
+fn requested_scopes(scopes: Option<Vec<String>>) -> Result<Vec<String>, Error> {
+    match scopes {
+        Some(mut scopes) => {
+            scopes.sort();
+            scopes.dedup();
+            validate_scopes(&scopes)?;
+            Ok(scopes)
+        }
+        None => Ok(SCOPES.iter().map(|scope| scope.to_string()).collect()),
+    }
+}
+
+pub struct AccountService<R, H, SS, EV, MF, LT>
 where
     R: AccountRepository,
     H: PasswordHasher,
-    G: AuthTokenGenerator,
+    SS: SessionService,
+    EV: EmailVerifier,
+    MF: MfaService,
+    LT: LoginThrottle,
 {
     repository: R,
     password_hasher: H,
-    token_generator: G,
+    session_service: SS,
+    email_verifier: EV,
+    mfa_service: MF,
+    login_throttle: LT,
+    password_policy: PasswordPolicy,
 }
 
-impl<R, H, G> AccountService<R, H, G>
+impl<R, H, SS, EV, MF, LT> AccountService<R, H, SS, EV, MF, LT>
 where
     R: AccountRepository,
     H: PasswordHasher,
-    G: AuthTokenGenerator,
+    SS: SessionService,
+    EV: EmailVerifier,
+    MF: MfaService,
+    LT: LoginThrottle,
 {
-    pub fn new(repository: R, password_hasher: H, token_generator: G) -> Self {
+    pub fn new(
+        repository: R,
+        password_hasher: H,
+        session_service: SS,
+        email_verifier: EV,
+        mfa_service: MF,
+        login_throttle: LT,
+        password_policy: PasswordPolicy,
+    ) -> Self {
         Self {
             repository,
             password_hasher,
-            token_generator: token_generator,
+            session_service,
+            email_verifier,
+            mfa_service,
+            login_throttle,
+            password_policy,
         }
     }
+
+    async fn rehash(&self, account_id: i32, password: String) -> Result<(), Error> {
+        let hashed_password = self.password_hasher.hash(password)?;
+        self.repository
+            .update_password(account_id, hashed_password)
+            .await
+    }
 }
 
 #[async_trait]
-impl<R, H, G> AccountServiceTrait for AccountService<R, H, G>
+impl<R, H, SS, EV, MF, LT> AccountServiceTrait for AccountService<R, H, SS, EV, MF, LT>
 where
     R: AccountRepository,
     H: PasswordHasher,
-    G: AuthTokenGenerator,
+    SS: SessionService,
+    EV: EmailVerifier,
+    MF: MfaService,
+    LT: LoginThrottle,
 {
-    async fn register(&self, request: RegisterRequest) -> Result<AuthToken, Error> {
+    async fn register(&self, request: RegisterRequest) -> Result<TokenPair, Error> {
+        let login = normalize_login(&request.login);
+        validate_login(&login)?;
+        self.password_policy
+            .validate("password", &login, &request.password)?;
+        if let Some(email) = &request.email {
+            validate_email(email)?;
+        }
         let hashed_password = self.password_hasher.hash(request.password)?;
         let create_account_request = CreateAccountRequest {
-            login: request.login,
+            login,
             password: hashed_password,
+            email: request.email,
         };
         let account = self.repository.create(create_account_request).await?;
-        self.token_generator.generate(account.id)
+        if let Some(email) = account.email
+            && let Err(err) = self.email_verifier.send(account.id, email).await
+        {
+            error!(
+                "Failed to send verification email to account {}: {:?}",
+                account.id, err
+            );
+        }
+        self.session_service
+            .start(CreateSessionRequest {
+                account_id: account.id,
+                client: request.client,
+                scopes: requested_scopes(None)?,
+            })
+            .await
     }
 
-    async fn login(&self, request: LoginRequest) -> Result<AuthToken, Error> {
-        let account = self.repository.get_by_login(request.login).await?;
+    async fn login(&self, request: LoginRequest) -> Result<LoginResult, Error> {
+        let scopes = requested_scopes(request.scopes)?;
+        let login = normalize_login(&request.login);
+        let ip_address = request.client.ip_address.clone();
+        self.login_throttle
+            .check(login.clone(), ip_address.clone())
+            .await?;
+        let account = match self.repository.get_by_login(login.clone()).await {
+            Ok(account) => account,
+            Err(Error::NotFound(_)) => {
+                self.login_throttle
+                    .record_failure(login, ip_address)
+                    .await?;
+                return Err(Error::Unauthorized("invalid login or password".to_string()));
+            }
+            Err(err) => return Err(err),
+        };
         let ok = self
             .password_hasher
-            .verify(request.password, account.password);
+            .verify(request.password.clone(), account.password.clone())?;
+        if !ok || account.is_disabled() {
+            self.login_throttle
+                .record_failure(login, ip_address)
+                .await?;
+            return Err(Error::Unauthorized("invalid login or password".to_string()));
+        }
+        if self.password_hasher.needs_rehash(&account.password)
+            && let Err(err) = self.rehash(account.id, request.password).await
+        {
+            error!(
+                "Failed to rehash password of account {}: {:?}",
+                account.id, err
+            );
+        }
+        if let Some(challenge) = self.mfa_service.challenge(&account, &scopes).await? {
+            return Ok(LoginResult::MfaRequired(challenge));
+        }
+        self.login_throttle
+            .record_success(login, ip_address)
+            .await?;
+        self.session_service
+            .start(CreateSessionRequest {
+                account_id: account.id,
+                client: request.client,
+                scopes,
+            })
+            .await
+            .map(LoginResult::Authenticated)
+    }
+
+    async fn login_mfa(&self, request: MfaLoginRequest) -> Result<TokenPair, Error> {
+        let pending = self.mfa_service.verify(request.verification).await?;
+        self.session_service
+            .start(CreateSessionRequest {
+                account_id: pending.account_id,
+                client: request.client,
+                scopes: pending.scopes,
+            })
+            .await
+    }
+
+    async fn refresh(&self, request: RefreshRequest) -> Result<TokenPair, Error> {
+        self.session_service.refresh(request).await
+    }
+
+    async fn authorize(&self, token: String) -> Result<AuthTokenClaims, Error> {
+        self.session_service.authorize(token).await
+    }
+
+    async fn logout(&self, claims: AuthTokenClaims) -> Result<(), Error> {
+        self.session_service.logout(claims).await
+    }
+
+    async fn logout_all(&self, account_id: i32) -> Result<(), Error> {
+        self.session_service.logout_all(account_id).await
+    }
+
+    async fn change_password(&self, request: ChangePasswordRequest) -> Result<(), Error> {
+        let account = self.repository.get(request.account_id).await?;
+        let ok = self
+            .password_hasher
+            .verify(request.current_password, account.password)?;
         if !ok {
-            return Err(Error::Unknown("Unauthorized".to_string()));
+            return Err(Error::Forbidden("invalid current password".to_string()));
+        }
+        self.password_policy
+            .validate("new_password", &account.login, &request.new_password)?;
+        let hashed_password = self.password_hasher.hash(request.new_password)?;
+        self.repository
+            .update_password(account.id, hashed_password)
+            .await?;
+        self.session_service
+            .logout_others(account.id, request.session_id)
+            .await
+    }
+
+    async fn verify_email(&self, request: VerifyEmailRequest) -> Result<(), Error> {
+        let claims = self.email_verifier.verify(request.token)?;
+        let account = self.repository.get(claims.account_id).await?;
+        if account.email.as_deref() != Some(claims.email.as_str()) {
+            return Err(Error::Forbidden("email address has changed".to_string()));
+        }
+        if account.email_verified_at.is_some() {
+            return Ok(());
+        }
+        self.repository
+            .verify_email(account.id, claims.email)
+            .await
+            .map(|_| ())
+    }
+
+    async fn resend_verification(&self, account_id: i32) -> Result<(), Error> {
+        let account = self.repository.get(account_id).await?;
+        if account.email_verified_at.is_some() {
+            return Err(Error::OperationNotApplicable(
+                "email address is already verified".to_string(),
+            ));
+        }
+        match account.email {
+            Some(email) => self.email_verifier.send(account.id, email).await,
+            None => Err(Error::OperationNotApplicable(
+                "account has no email address".to_string(),
+            )),
         }
-        self.token_generator.generate(account.id)
     }
 
-    async fn authorize(&self, token: String) -> Result<i32, Error> {
-        self.token_generator.parse(token)
+    async fn require_verified(&self, account_id: i32) -> Result<(), Error> {
+        if !self.email_verifier.is_required() {
+            return Ok(());
+        }
+        let account = self.repository.get(account_id).await?;
+        if account.verified_email().is_none() {
+            return Err(Error::Forbidden(
+                "email address is not verified".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn sessions(&self, account_id: i32) -> Result<Vec<Session>, Error> {
+        self.session_service.list(account_id).await
+    }
+
+    async fn delete_session(&self, request: DeleteSessionRequest) -> Result<(), Error> {
+        self.session_service.delete(request).await
     }
 }
@@ -0,0 +1,66 @@
+use crate::domain::{
+    Account, AccountFilters, AccountRepository, AdminService as AdminServiceTrait, Error, Role,
+    SessionService,
+};
+
+pub struct AdminService<R, SS>
+where
+    R: AccountRepository,
+    SS: SessionService,
+{
+    repository: R,
+    session_service: SS,
+}
+
+impl<R, SS> AdminService<R, SS>
+where
+    R: AccountRepository,
+    SS: SessionService,
+{
+    pub fn new(repository: R, session_service: SS) -> Self {
+        Self {
+            repository,
+            session_service,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, SS> AdminServiceTrait for AdminService<R, SS>
+where
+    R: AccountRepository,
+    SS: SessionService,
+{
+    async fn require_admin(&self, account_id: i32) -> Result<(), Error> {
+        let account = self.repository.get(account_id).await?;
+        if account.role != Role::Admin || account.is_disabled() {
+            return Err(Error::Forbidden("admin role is required".to_string()));
+        }
+        Ok(())
+    }
+
+    async fn accounts(&self, filters: AccountFilters) -> Result<(Vec<Account>, i64), Error> {
+        let accounts = self.repository.list(&filters).await?;
+        let total = self.repository.count().await?;
+        Ok((accounts, total))
+    }
+
+    async fn disable(&self, admin_id: i32, account_id: i32) -> Result<(), Error> {
+        if admin_id == account_id {
+            return Err(Error::OperationNotApplicable(
+                "cannot disable own account".to_string(),
+            ));
+        }
+        if !self.repository.set_disabled(account_id, true).await? {
+            self.repository.get(account_id).await?;
+        }
+        self.session_service.logout_all(account_id).await
+    }
+
+    async fn enable(&self, account_id: i32) -> Result<(), Error> {
+        if !self.repository.set_disabled(account_id, false).await? {
+            self.repository.get(account_id).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::domain::{
+    EmailVerificationClaims, EmailVerificationSigner, EmailVerifier, Error, Mail, Mailer,
+};
+
+pub struct EmailVerificationConfig {
+    pub verification_url: String,
+    pub required: bool,
+}
+
+pub struct EmailVerificationService<M, V>
+where
+    M: Mailer,
+    V: EmailVerificationSigner,
+{
+    mailer: M,
+    signer: V,
+    config: EmailVerificationConfig,
+}
+
+impl<M, V> EmailVerificationService<M, V>
+where
+    M: Mailer,
+    V: EmailVerificationSigner,
+{
+    pub fn new(mailer: M, signer: V, config: EmailVerificationConfig) -> Self {
+        Self {
+            mailer,
+            signer,
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl<M, V> EmailVerifier for EmailVerificationService<M, V>
+where
+    M: Mailer,
+    V: EmailVerificationSigner,
+{
+    async fn send(&self, account_id: i32, email: String) -> Result<(), Error> {
+        let token = self.signer.sign(&EmailVerificationClaims {
+            account_id,
+            email: email.clone(),
+        })?;
+        self.mailer
+            .send(Mail {
+                to: email,
+                subject: "Verify your email address".to_string(),
+                body: format!(
+                    "Use the link below to verify your email address.\n\n{}{}",
+                    self.config.verification_url, token
+                ),
+            })
+            .await
+    }
+
+    fn verify(&self, token: String) -> Result<EmailVerificationClaims, Error> {
+        self.signer.verify(token)
+    }
+
+    fn is_required(&self) -> bool {
+        self.config.required
+    }
+}
@@ -0,0 +1,113 @@
+use crate::domain::{
+    Error, LoginAttemptOutcome, LoginAttemptRepository, LoginFailures,
+    LoginThrottle as LoginThrottleTrait, RecordLoginAttemptRequest,
+};
+
+const MAX_BACKOFF_EXPONENT: i64 = 20;
+
+pub struct LoginThrottleConfig {
+    pub window: time::Duration,
+    pub max_failures_per_login: i64,
+    pub max_failures_per_ip: i64,
+    pub lockout_base: time::Duration,
+    pub lockout_max: time::Duration,
+}
+
+pub struct LoginThrottle<R>
+where
+    R: LoginAttemptRepository,
+{
+    repository: R,
+    config: LoginThrottleConfig,
+}
+
+impl<R> LoginThrottle<R>
+where
+    R: LoginAttemptRepository,
+{
+    pub fn new(repository: R, config: LoginThrottleConfig) -> Self {
+        Self { repository, config }
+    }
+
+    fn lockout_remaining(
+        &self,
+        failures: &LoginFailures,
+        max_failures: i64,
+    ) -> Option<time::Duration> {
+        if failures.count < max_failures {
+            return None;
+        }
+        let exponent = (failures.count - max_failures).min(MAX_BACKOFF_EXPONENT) as i32;
+        let lockout =
+            (self.config.lockout_base * 2_f64.powi(exponent)).min(self.config.lockout_max);
+        let remaining = failures.last_failed_at? + lockout - time::OffsetDateTime::now_utc();
+        if remaining.is_positive() {
+            Some(remaining)
+        } else {
+            None
+        }
+    }
+
+    async fn record(
+        &self,
+        login: String,
+        ip_address: Option<String>,
+        outcome: LoginAttemptOutcome,
+    ) -> Result<(), Error> {
+        self.repository
+            .record(RecordLoginAttemptRequest {
+                login,
+                ip_address,
+                outcome,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl<R> LoginThrottleTrait for LoginThrottle<R>
+where
+    R: LoginAttemptRepository,
+{
+    async fn check(&self, login: String, ip_address: Option<String>) -> Result<(), Error> {
+        let by_login = self
+            .repository
+            .failures_by_login(login.clone(), self.config.window)
+            .await?;
+        let mut remaining = self.lockout_remaining(&by_login, self.config.max_failures_per_login);
+
+        if let Some(ip_address) = &ip_address {
+            let by_ip = self
+                .repository
+                .failures_by_ip(ip_address.clone(), self.config.window)
+                .await?;
+            remaining =
+                remaining.max(self.lockout_remaining(&by_ip, self.config.max_failures_per_ip));
+        }
+
+        match remaining {
+            Some(remaining) => {
+                warn!(
+                    "Rejected login attempt for {:?} from {:?}: locked for {}s after repeated failures.",
+                    login,
+                    ip_address,
+                    remaining.whole_seconds()
+                );
+                self.record(login, ip_address, LoginAttemptOutcome::Locked)
+                    .await?;
+                Err(Error::RateLimited(remaining))
+            }
+            None => Ok(()),
+        }
+    }
+
+    async fn record_failure(&self, login: String, ip_address: Option<String>) -> Result<(), Error> {
+        self.record(login, ip_address, LoginAttemptOutcome::Failure)
+            .await
+    }
+
+    async fn record_success(&self, login: String, ip_address: Option<String>) -> Result<(), Error> {
+        self.record(login, ip_address, LoginAttemptOutcome::Success)
+            .await
+    }
+}
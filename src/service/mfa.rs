@@ -0,0 +1,206 @@
+use crate::domain::{
+    Account, AccountRepository, ConfirmTotpRequest, Error, LoginThrottle, MfaChallenge,
+    MfaService as MfaServiceTrait, MfaTokenSigner, PendingMfaLogin, RecoveryCodeRepository,
+    SecretGenerator, TotpEnrollment, TotpVerifier, VerifyMfaRequest,
+};
+
+const RECOVERY_CODE_COUNT: usize = 10;
+
+fn normalize_recovery_code(code: &str) -> String {
+    code.trim().to_lowercase()
+}
+
+pub struct MfaService<R, T, RC, MS, S, LT>
+where
+    R: AccountRepository,
+    T: TotpVerifier,
+    RC: RecoveryCodeRepository,
+    MS: MfaTokenSigner,
+    S: SecretGenerator,
+    LT: LoginThrottle,
+{
+    repository: R,
+    totp_verifier: T,
+    recovery_code_repository: RC,
+    token_signer: MS,
+    secret_generator: S,
+    login_throttle: LT,
+}
+
+impl<R, T, RC, MS, S, LT> MfaService<R, T, RC, MS, S, LT>
+where
+    R: AccountRepository,
+    T: TotpVerifier,
+    RC: RecoveryCodeRepository,
+    MS: MfaTokenSigner,
+    S: SecretGenerator,
+    LT: LoginThrottle,
+{
+    pub fn new(
+        repository: R,
+        totp_verifier: T,
+        recovery_code_repository: RC,
+        token_signer: MS,
+        secret_generator: S,
+        login_throttle: LT,
+    ) -> Self {
+        Self {
+            repository,
+            totp_verifier,
+            recovery_code_repository,
+            token_signer,
+            secret_generator,
+            login_throttle,
+        }
+    }
+
+    async fn use_totp_code(&self, account_id: i32, secret: &str, code: &str) -> Result<(), Error> {
+        let step = self
+            .totp_verifier
+            .verify(secret, code)?
+            .ok_or_else(|| Error::Unauthorized("invalid code".to_string()))?;
+        if !self.repository.accept_totp_step(account_id, step).await? {
+            return Err(Error::Unauthorized(
+                "code has already been used".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    async fn use_recovery_code(&self, account_id: i32, code: &str) -> Result<(), Error> {
+        let code_hash = self.secret_generator.hash(&normalize_recovery_code(code));
+        let codes = self
+            .recovery_code_repository
+            .list_unused(account_id)
+            .await?;
+        let stored = codes
+            .into_iter()
+            .find(|stored| stored.code_hash == code_hash)
+            .ok_or_else(|| Error::Unauthorized("invalid recovery code".to_string()))?;
+        if !self.recovery_code_repository.mark_used(stored.id).await? {
+            return Err(Error::Unauthorized("invalid recovery code".to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R, T, RC, MS, S, LT> MfaServiceTrait for MfaService<R, T, RC, MS, S, LT>
+where
+    R: AccountRepository,
+    T: TotpVerifier,
+    RC: RecoveryCodeRepository,
+    MS: MfaTokenSigner,
+    S: SecretGenerator,
+    LT: LoginThrottle,
+{
+    async fn enroll(&self, account_id: i32) -> Result<TotpEnrollment, Error> {
+        let account = self.repository.get(account_id).await?;
+        if account.totp_enabled_at.is_some() {
+            return Err(Error::OperationNotApplicable(
+                "two-factor authentication is already enabled".to_string(),
+            ));
+        }
+        let secret = self.totp_verifier.generate_secret();
+        let uri = self
+            .totp_verifier
+            .provisioning_uri(&secret, &account.login)?;
+        self.repository
+            .set_totp_secret(account.id, secret.clone())
+            .await?;
+        Ok(TotpEnrollment { secret, uri })
+    }
+
+    async fn confirm(&self, request: ConfirmTotpRequest) -> Result<Vec<String>, Error> {
+        let account = self.repository.get(request.account_id).await?;
+        if account.totp_enabled_at.is_some() {
+            return Err(Error::OperationNotApplicable(
+                "two-factor authentication is already enabled".to_string(),
+            ));
+        }
+        let secret = account.totp_secret.ok_or_else(|| {
+            Error::OperationNotApplicable(
+                "two-factor authentication enrollment has not been started".to_string(),
+            )
+        })?;
+        match self.use_totp_code(account.id, &secret, &request.code).await {
+            Ok(()) => {}
+            Err(Error::Unauthorized(message)) => return Err(Error::Forbidden(message)),
+            Err(err) => return Err(err),
+        }
+
+        let codes: Vec<String> = (0..RECOVERY_CODE_COUNT)
+            .map(|_| self.secret_generator.generate_code())
+            .collect();
+        let code_hashes = codes
+            .iter()
+            .map(|code| self.secret_generator.hash(code))
+            .collect();
+        self.recovery_code_repository
+            .replace(account.id, code_hashes)
+            .await?;
+        self.repository.enable_totp(account.id).await?;
+        Ok(codes)
+    }
+
+    async fn challenge(
+        &self,
+        account: &Account,
+        scopes: &[String],
+    ) -> Result<Option<MfaChallenge>, Error> {
+        if account.totp_enabled_at.is_none() {
+            return Ok(None);
+        }
+        self.token_signer
+            .sign(&PendingMfaLogin {
+                account_id: account.id,
+                scopes: scopes.to_vec(),
+            })
+            .map(Some)
+    }
+
+    async fn verify(&self, request: VerifyMfaRequest) -> Result<PendingMfaLogin, Error> {
+        let pending = self.token_signer.verify(request.mfa_token)?;
+        let account = self.repository.get(pending.account_id).await?;
+        if account.is_disabled() {
+            return Err(Error::Forbidden("account is disabled".to_string()));
+        }
+        let secret = match (account.totp_secret, account.totp_enabled_at) {
+            (Some(secret), Some(_)) => secret,
+            _ => {
+                return Err(Error::Unauthorized(
+                    "two-factor authentication is not enabled".to_string(),
+                ));
+            }
+        };
+
+        self.login_throttle
+            .check(account.login.clone(), request.ip_address.clone())
+            .await?;
+        let result = match (request.code, request.recovery_code) {
+            (Some(code), _) => self.use_totp_code(account.id, &secret, &code).await,
+            (None, Some(recovery_code)) => self.use_recovery_code(account.id, &recovery_code).await,
+            (None, None) => {
+                return Err(Error::validation(
+                    "code",
+                    "code or recovery_code is required",
+                ));
+            }
+        };
+        match result {
+            Ok(()) => {
+                self.login_throttle
+                    .record_success(account.login, request.ip_address)
+                    .await?;
+                Ok(pending)
+            }
+            Err(Error::Unauthorized(message)) => {
+                self.login_throttle
+                    .record_failure(account.login, request.ip_address)
+                    .await?;
+                Err(Error::Unauthorized(message))
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
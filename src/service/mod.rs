@@ -1,2 +1,14 @@
+pub mod access_token;
 pub mod account;
+pub mod admin;
+pub mod email_verification;
+pub mod login_throttle;
+pub mod mfa;
+pub mod password_policy;
+pub mod password_reset;
+pub mod personal_data;
+pub mod project;
+pub mod session;
+pub mod tag;
 pub mod todo;
+pub mod trash;
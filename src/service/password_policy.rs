@@ -0,0 +1,81 @@
+use std::{collections::HashSet, sync::Arc};
+
+use crate::domain::Error;
+
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456",
+    "123456789",
+    "12345678",
+    "1234567890",
+    "password",
+    "password1",
+    "qwerty",
+    "qwerty123",
+    "qwertyuiop",
+    "111111",
+    "000000",
+    "abc123",
+    "iloveyou",
+    "letmein",
+    "welcome",
+    "admin",
+    "admin123",
+    "monkey",
+    "dragon",
+    "football",
+    "sunshine",
+    "princess",
+];
+
+#[derive(Clone)]
+pub struct PasswordPolicy {
+    min_length: usize,
+    max_length: usize,
+    banned: Arc<HashSet<String>>,
+}
+
+impl PasswordPolicy {
+    pub fn new(min_length: usize, max_length: usize, banned_list: Option<&str>) -> Self {
+        let mut banned: HashSet<String> = COMMON_PASSWORDS
+            .iter()
+            .map(|password| password.to_string())
+            .collect();
+        if let Some(banned_list) = banned_list {
+            banned.extend(
+                banned_list
+                    .lines()
+                    .map(|password| password.trim().to_lowercase())
+                    .filter(|password| !password.is_empty()),
+            );
+        }
+        Self {
+            min_length,
+            max_length,
+            banned: Arc::new(banned),
+        }
+    }
+
+    pub fn validate(&self, field: &str, login: &str, password: &str) -> Result<(), Error> {
+        let length = password.chars().count();
+        if length < self.min_length {
+            return Err(Error::validation(
+                field,
+                format!("must be at least {} characters long", self.min_length),
+            ));
+        }
+        if length > self.max_length {
+            return Err(Error::validation(
+                field,
+                format!("must be at most {} characters long", self.max_length),
+            ));
+        }
+        let normalized = password.to_lowercase();
+        if normalized == login || self.banned.contains(&normalized) {
+            return Err(Error::validation(
+                field,
+                "is too common or matches the login",
+            ));
+        }
+        Ok(())
+    }
+}
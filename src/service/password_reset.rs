@@ -0,0 +1,158 @@
+use crate::{
+    domain::{
+        AccountRepository, CreatePasswordResetTokenRequest, Error, ForgotPasswordRequest, Mail,
+        Mailer, PasswordHasher, PasswordResetService as PasswordResetServiceTrait,
+        PasswordResetTokenRepository, ResetPasswordRequest, SecretGenerator, SessionService,
+        normalize_login,
+    },
+    service::password_policy::PasswordPolicy,
+};
+
+pub struct PasswordResetConfig {
+    pub token_duration: time::Duration,
+    pub reset_url: String,
+    pub password_policy: PasswordPolicy,
+}
+
+pub struct PasswordResetService<R, H, PR, S, M, SS>
+where
+    R: AccountRepository,
+    H: PasswordHasher,
+    PR: PasswordResetTokenRepository + Clone + 'static,
+    S: SecretGenerator,
+    M: Mailer + Clone + 'static,
+    SS: SessionService,
+{
+    repository: R,
+    password_hasher: H,
+    token_repository: PR,
+    secret_generator: S,
+    mailer: M,
+    session_service: SS,
+    config: PasswordResetConfig,
+}
+
+impl<R, H, PR, S, M, SS> PasswordResetService<R, H, PR, S, M, SS>
+where
+    R: AccountRepository,
+    H: PasswordHasher,
+    PR: PasswordResetTokenRepository + Clone + 'static,
+    S: SecretGenerator,
+    M: Mailer + Clone + 'static,
+    SS: SessionService,
+{
+    pub fn new(
+        repository: R,
+        password_hasher: H,
+        token_repository: PR,
+        secret_generator: S,
+        mailer: M,
+        session_service: SS,
+        config: PasswordResetConfig,
+    ) -> Self {
+        Self {
+            repository,
+            password_hasher,
+            token_repository,
+            secret_generator,
+            mailer,
+            session_service,
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, H, PR, S, M, SS> PasswordResetServiceTrait for PasswordResetService<R, H, PR, S, M, SS>
+where
+    R: AccountRepository,
+    H: PasswordHasher,
+    PR: PasswordResetTokenRepository + Clone + 'static,
+    S: SecretGenerator,
+    M: Mailer + Clone + 'static,
+    SS: SessionService,
+{
+    async fn forgot(&self, request: ForgotPasswordRequest) -> Result<(), Error> {
+        let account = match self
+            .repository
+            .get_by_login(normalize_login(&request.login))
+            .await
+        {
+            Ok(account) => account,
+            Err(Error::NotFound(_)) => return Ok(()),
+            Err(err) => return Err(err),
+        };
+
+        let email = match account.verified_email() {
+            Some(email) => email.to_string(),
+            None => return Ok(()),
+        };
+
+        let secret = self.secret_generator.generate();
+        let mail = Mail {
+            to: email,
+            subject: "Password reset".to_string(),
+            body: format!(
+                "Use the link below to reset your password. It expires in {} minutes.\n\n{}{}",
+                self.config.token_duration.whole_minutes(),
+                self.config.reset_url,
+                secret.value
+            ),
+        };
+        let request = CreatePasswordResetTokenRequest {
+            account_id: account.id,
+            token_hash: secret.hash,
+            duration: self.config.token_duration,
+        };
+        let token_repository = self.token_repository.clone();
+        let mailer = self.mailer.clone();
+        // Issue the token and send the mail off the request path, so that the
+        // response time and status don't reveal whether the account exists.
+        rocket::tokio::spawn(async move {
+            let account_id = request.account_id;
+            let result = match token_repository.create(request).await {
+                Ok(_) => mailer.send(mail).await,
+                Err(err) => Err(err),
+            };
+            if let Err(err) = result {
+                error!(
+                    "Failed to send password reset email to account {}: {:?}",
+                    account_id, err
+                );
+            }
+        });
+        Ok(())
+    }
+
+    async fn reset(&self, request: ResetPasswordRequest) -> Result<(), Error> {
+        let token_hash = self.secret_generator.hash(&request.token);
+        let stored = match self.token_repository.get_by_hash(token_hash).await {
+            Ok(stored) => stored,
+            Err(Error::NotFound(_)) => {
+                return Err(Error::Forbidden("unknown reset token".to_string()));
+            }
+            Err(err) => return Err(err),
+        };
+
+        if stored.used_at.is_some() || stored.expires_at <= time::OffsetDateTime::now_utc() {
+            return Err(Error::Forbidden("reset token is expired".to_string()));
+        }
+
+        let account = self.repository.get(stored.account_id).await?;
+        self.config.password_policy.validate(
+            "new_password",
+            &account.login,
+            &request.new_password,
+        )?;
+
+        if !self.token_repository.mark_used(stored.id).await? {
+            return Err(Error::Forbidden("reset token is expired".to_string()));
+        }
+
+        let hashed_password = self.password_hasher.hash(request.new_password)?;
+        self.repository
+            .update_password(stored.account_id, hashed_password)
+            .await?;
+        self.session_service.logout_all(stored.account_id).await
+    }
+}
@@ -0,0 +1,113 @@
+use crate::domain::{
+    AccountExport, AccountRepository, DeleteAccountRequest, Error, Filters, PasswordHasher,
+    PersonalDataService as PersonalDataServiceTrait, ProjectFilters, ProjectRepository, TagMatch,
+    TagRepository, TodoLister,
+};
+
+pub struct PersonalDataService<R, H, TL, PR, TR>
+where
+    R: AccountRepository,
+    H: PasswordHasher,
+    TL: TodoLister,
+    PR: ProjectRepository,
+    TR: TagRepository,
+{
+    repository: R,
+    password_hasher: H,
+    todo_lister: TL,
+    project_repository: PR,
+    tag_repository: TR,
+}
+
+impl<R, H, TL, PR, TR> PersonalDataService<R, H, TL, PR, TR>
+where
+    R: AccountRepository,
+    H: PasswordHasher,
+    TL: TodoLister,
+    PR: ProjectRepository,
+    TR: TagRepository,
+{
+    pub fn new(
+        repository: R,
+        password_hasher: H,
+        todo_lister: TL,
+        project_repository: PR,
+        tag_repository: TR,
+    ) -> Self {
+        Self {
+            repository,
+            password_hasher,
+            todo_lister,
+            project_repository,
+            tag_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R, H, TL, PR, TR> PersonalDataServiceTrait for PersonalDataService<R, H, TL, PR, TR>
+where
+    R: AccountRepository,
+    H: PasswordHasher,
+    TL: TodoLister,
+    PR: ProjectRepository,
+    TR: TagRepository,
+{
+    async fn export(&self, account_id: i32) -> Result<AccountExport, Error> {
+        let account = self.repository.get(account_id).await?;
+        let mut todo_items = Vec::new();
+        for trashed in [false, true] {
+            let items = self
+                .todo_lister
+                .list(&Filters {
+                    owner_id: account.id,
+                    status: None,
+                    trashed,
+                    project: None,
+                    due_before: None,
+                    due_after: None,
+                    overdue: false,
+                    tags: Vec::new(),
+                    tag_match: TagMatch::Any,
+                    sort: Vec::new(),
+                    limit: None,
+                    offset: None,
+                })
+                .await?;
+            todo_items.extend(items);
+        }
+        let mut projects = Vec::new();
+        for archived in [false, true] {
+            let items = self
+                .project_repository
+                .list(&ProjectFilters {
+                    owner_id: account.id,
+                    archived,
+                })
+                .await?;
+            projects.extend(items);
+        }
+        let tags = self.tag_repository.list(account.id).await?;
+        Ok(AccountExport {
+            account,
+            todo_items,
+            projects,
+            tags,
+            exported_at: time::OffsetDateTime::now_utc(),
+        })
+    }
+
+    async fn delete(&self, request: DeleteAccountRequest) -> Result<(), Error> {
+        let account = self.repository.get(request.account_id).await?;
+        let ok = self
+            .password_hasher
+            .verify(request.password, account.password)?;
+        if !ok {
+            return Err(Error::Forbidden("invalid password".to_string()));
+        }
+        if !self.repository.delete(account.id).await? {
+            return Err(Error::NotFound(format!("account {} not found", account.id)));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use crate::domain::{
+    ArchiveProjectRequest, CreateProjectRequest, DeleteProjectMode, DeleteProjectRequest, Error,
+    Project, ProjectFilters, ProjectRepository, ProjectService as ProjectServiceTrait,
+    RenameProjectRequest,
+};
+
+const NAME_MAX_LENGTH: usize = 100;
+
+fn validate_name(name: &str) -> Result<(), Error> {
+    if name.trim().is_empty() {
+        return Err(Error::validation("name", "must not be empty"));
+    }
+    if name.chars().count() > NAME_MAX_LENGTH {
+        return Err(Error::validation(
+            "name",
+            format!("must be at most {} characters long", NAME_MAX_LENGTH),
+        ));
+    }
+    Ok(())
+}
+
+pub struct ProjectService<R>
+where
+    R: ProjectRepository,
+{
+    repository: R,
+}
+
+impl<R> ProjectService<R>
+where
+    R: ProjectRepository,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R> ProjectServiceTrait for ProjectService<R>
+where
+    R: ProjectRepository,
+{
+    async fn create(&self, request: CreateProjectRequest) -> Result<Project, Error> {
+        validate_name(&request.name)?;
+        self.repository
+            .create(CreateProjectRequest {
+                name: request.name.trim().to_string(),
+                ..request
+            })
+            .await
+    }
+
+    async fn list(&self, filters: ProjectFilters) -> Result<Vec<Project>, Error> {
+        self.repository.list(&filters).await
+    }
+
+    async fn rename(&self, request: RenameProjectRequest) -> Result<Project, Error> {
+        validate_name(&request.name)?;
+        self.repository
+            .rename(RenameProjectRequest {
+                name: request.name.trim().to_string(),
+                ..request
+            })
+            .await
+    }
+
+    async fn archive(&self, request: ArchiveProjectRequest) -> Result<Project, Error> {
+        let project = self
+            .repository
+            .get(request.owner_id, request.project_id)
+            .await?;
+        if project.is_archived() == request.archived {
+            return Err(Error::OperationNotApplicable(if request.archived {
+                "project is already archived".to_string()
+            } else {
+                "project is not archived".to_string()
+            }));
+        }
+        self.repository.set_archived(request).await
+    }
+
+    async fn delete(&self, request: DeleteProjectRequest) -> Result<(), Error> {
+        if let DeleteProjectMode::Move {
+            target_id: Some(target_id),
+        } = request.mode
+            && target_id == request.project_id
+        {
+            return Err(Error::validation(
+                "target",
+                "must differ from the deleted project",
+            ));
+        }
+        let project_id = request.project_id;
+        if !self.repository.delete(request).await? {
+            return Err(Error::NotFound(format!("project {} not found", project_id)));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use crate::domain::{
+    AuthTokenClaims, AuthTokenGenerator, CreateRefreshTokenRequest, CreateSessionRequest,
+    DeleteSessionRequest, Error, RefreshRequest, RefreshTokenRepository, RevokeTokenRequest,
+    SecretGenerator, Session, SessionRepository, SessionService as SessionServiceTrait, TokenPair,
+    TokenRevocationStore,
+};
+
+pub struct SessionService<G, RT, S, TR, SE>
+where
+    G: AuthTokenGenerator,
+    RT: RefreshTokenRepository,
+    S: SecretGenerator,
+    TR: TokenRevocationStore,
+    SE: SessionRepository,
+{
+    token_generator: G,
+    refresh_token_repository: RT,
+    secret_generator: S,
+    revocation_store: TR,
+    session_repository: SE,
+    refresh_token_duration: time::Duration,
+}
+
+impl<G, RT, S, TR, SE> SessionService<G, RT, S, TR, SE>
+where
+    G: AuthTokenGenerator,
+    RT: RefreshTokenRepository,
+    S: SecretGenerator,
+    TR: TokenRevocationStore,
+    SE: SessionRepository,
+{
+    pub fn new(
+        token_generator: G,
+        refresh_token_repository: RT,
+        secret_generator: S,
+        revocation_store: TR,
+        session_repository: SE,
+        refresh_token_duration: time::Duration,
+    ) -> Self {
+        Self {
+            token_generator,
+            refresh_token_repository,
+            secret_generator,
+            revocation_store,
+            session_repository,
+            refresh_token_duration,
+        }
+    }
+
+    async fn issue_tokens(
+        &self,
+        account_id: i32,
+        session_id: String,
+        scopes: Vec<String>,
+    ) -> Result<TokenPair, Error> {
+        let secret = self.secret_generator.generate();
+        let refresh_token = self
+            .refresh_token_repository
+            .create(CreateRefreshTokenRequest {
+                account_id,
+                family_id: session_id,
+                token_hash: secret.hash,
+                duration: self.refresh_token_duration,
+            })
+            .await?;
+        let access_token =
+            self.token_generator
+                .generate(account_id, refresh_token.family_id, scopes)?;
+        Ok(TokenPair {
+            access_token,
+            refresh_token: secret.value,
+            refresh_expires: refresh_token.expires_at,
+        })
+    }
+
+    async fn revoke_session(&self, session_id: String) -> Result<(), Error> {
+        self.refresh_token_repository
+            .revoke_family(session_id.clone())
+            .await?;
+        self.revocation_store.revoke_session(session_id).await
+    }
+}
+
+#[async_trait]
+impl<G, RT, S, TR, SE> SessionServiceTrait for SessionService<G, RT, S, TR, SE>
+where
+    G: AuthTokenGenerator,
+    RT: RefreshTokenRepository,
+    S: SecretGenerator,
+    TR: TokenRevocationStore,
+    SE: SessionRepository,
+{
+    async fn start(&self, request: CreateSessionRequest) -> Result<TokenPair, Error> {
+        let account_id = request.account_id;
+        let session = self.session_repository.create(request).await?;
+        self.issue_tokens(account_id, session.id, session.scopes)
+            .await
+    }
+
+    async fn refresh(&self, request: RefreshRequest) -> Result<TokenPair, Error> {
+        let token_hash = self.secret_generator.hash(&request.refresh_token);
+        let stored = match self.refresh_token_repository.get_by_hash(token_hash).await {
+            Ok(stored) => stored,
+            Err(Error::NotFound(_)) => {
+                return Err(Error::Unauthorized("unknown refresh token".to_string()));
+            }
+            Err(err) => return Err(err),
+        };
+
+        if stored.revoked_at.is_some() {
+            return Err(Error::Unauthorized("refresh token is revoked".to_string()));
+        }
+
+        if stored.used_at.is_some() || !self.refresh_token_repository.mark_used(stored.id).await? {
+            self.revoke_session(stored.family_id).await?;
+            return Err(Error::Unauthorized(
+                "refresh token reuse detected".to_string(),
+            ));
+        }
+
+        if stored.expires_at <= time::OffsetDateTime::now_utc() {
+            return Err(Error::Unauthorized("refresh token is expired".to_string()));
+        }
+
+        let session = match self.session_repository.get(stored.family_id).await {
+            Ok(session) => session,
+            Err(Error::NotFound(_)) => {
+                return Err(Error::Unauthorized("session is revoked".to_string()));
+            }
+            Err(err) => return Err(err),
+        };
+        self.session_repository
+            .touch(vec![session.id.clone()])
+            .await?;
+        self.issue_tokens(stored.account_id, session.id, session.scopes)
+            .await
+    }
+
+    async fn authorize(&self, token: String) -> Result<AuthTokenClaims, Error> {
+        let claims = self.token_generator.parse(token)?;
+        if self.revocation_store.is_revoked(&claims).await? {
+            return Err(Error::Unauthorized("token is revoked".to_string()));
+        }
+        self.session_repository
+            .touch(vec![claims.session_id.clone()])
+            .await?;
+        Ok(claims)
+    }
+
+    async fn logout(&self, claims: AuthTokenClaims) -> Result<(), Error> {
+        self.revoke_session(claims.session_id.clone()).await?;
+        self.revocation_store
+            .revoke_token(RevokeTokenRequest {
+                account_id: claims.account_id,
+                session_id: claims.session_id,
+                token_id: claims.token_id,
+                expires: claims.expires,
+            })
+            .await
+    }
+
+    async fn logout_all(&self, account_id: i32) -> Result<(), Error> {
+        self.refresh_token_repository
+            .revoke_account(account_id)
+            .await?;
+        self.revocation_store.revoke_account(account_id).await
+    }
+
+    async fn logout_others(&self, account_id: i32, session_id: String) -> Result<(), Error> {
+        self.refresh_token_repository
+            .revoke_other_families(account_id, session_id.clone())
+            .await?;
+        self.revocation_store
+            .revoke_other_sessions(account_id, session_id)
+            .await
+    }
+
+    async fn list(&self, account_id: i32) -> Result<Vec<Session>, Error> {
+        self.session_repository.list(account_id).await
+    }
+
+    async fn delete(&self, request: DeleteSessionRequest) -> Result<(), Error> {
+        let session = self
+            .session_repository
+            .get(request.session_id.clone())
+            .await?;
+        if session.account_id != request.account_id {
+            return Err(Error::NotFound(format!(
+                "session {} not found",
+                request.session_id
+            )));
+        }
+        self.revoke_session(session.id).await
+    }
+}
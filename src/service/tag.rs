@@ -0,0 +1,142 @@
+use crate::domain::{
+    CreateTagRequest, DeleteTagRequest, Error, GetTodoItemRequest, Tag, TagItemRequest,
+    TagRepository, TagService as TagServiceTrait, TodoGetter, TodoItem, UpdateTagRequest,
+};
+
+const NAME_MAX_LENGTH: usize = 50;
+
+fn validate_name(name: &str) -> Result<(), Error> {
+    if name.trim().is_empty() {
+        return Err(Error::validation("name", "must not be empty"));
+    }
+    if name.chars().count() > NAME_MAX_LENGTH {
+        return Err(Error::validation(
+            "name",
+            format!("must be at most {} characters long", NAME_MAX_LENGTH),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_color(color: &str) -> Result<(), Error> {
+    let valid = match color.strip_prefix('#') {
+        Some(hex) => hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => false,
+    };
+    if !valid {
+        return Err(Error::validation(
+            "color",
+            "must be a hex color such as #1e90ff",
+        ));
+    }
+    Ok(())
+}
+
+pub struct TagService<R, G>
+where
+    R: TagRepository,
+    G: TodoGetter,
+{
+    repository: R,
+    getter: G,
+}
+
+impl<R, G> TagService<R, G>
+where
+    R: TagRepository,
+    G: TodoGetter,
+{
+    pub fn new(repository: R, getter: G) -> Self {
+        Self { repository, getter }
+    }
+
+    async fn item(&self, request: &TagItemRequest) -> Result<TodoItem, Error> {
+        self.getter
+            .one(GetTodoItemRequest {
+                owner_id: request.owner_id,
+                item_id: request.item_id,
+                include_trashed: false,
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl<R, G> TagServiceTrait for TagService<R, G>
+where
+    R: TagRepository,
+    G: TodoGetter,
+{
+    async fn create(&self, request: CreateTagRequest) -> Result<Tag, Error> {
+        validate_name(&request.name)?;
+        if let Some(color) = &request.color {
+            validate_color(color)?;
+        }
+        self.repository
+            .create(CreateTagRequest {
+                name: request.name.trim().to_string(),
+                ..request
+            })
+            .await
+    }
+
+    async fn list(&self, owner_id: i32) -> Result<Vec<Tag>, Error> {
+        self.repository.list(owner_id).await
+    }
+
+    async fn update(&self, request: UpdateTagRequest) -> Result<Tag, Error> {
+        if let Some(name) = &request.name {
+            validate_name(name)?;
+        }
+        if let Some(Some(color)) = &request.color {
+            validate_color(color)?;
+        }
+        self.repository
+            .update(UpdateTagRequest {
+                name: request.name.as_deref().map(|name| name.trim().to_string()),
+                ..request
+            })
+            .await
+    }
+
+    async fn delete(&self, request: DeleteTagRequest) -> Result<(), Error> {
+        let tag_id = request.tag_id;
+        if !self.repository.delete(request).await? {
+            return Err(Error::NotFound(format!("tag {} not found", tag_id)));
+        }
+        Ok(())
+    }
+
+    async fn attach(&self, request: TagItemRequest) -> Result<TodoItem, Error> {
+        let item = self.item(&request).await?;
+        let tag = self
+            .repository
+            .get(request.owner_id, request.tag_id)
+            .await?;
+        self.repository.attach(item.id, tag.id).await?;
+        self.getter
+            .one(GetTodoItemRequest {
+                owner_id: request.owner_id,
+                item_id: item.id,
+                include_trashed: false,
+            })
+            .await
+    }
+
+    async fn detach(&self, request: TagItemRequest) -> Result<TodoItem, Error> {
+        let item = self.item(&request).await?;
+        if !self.repository.detach(item.id, request.tag_id).await? {
+            return Err(Error::NotFound(format!(
+                "tag {} is not attached to item {}",
+                request.tag_id, item.id
+            )));
+        }
+        self.getter
+            .one(GetTodoItemRequest {
+                owner_id: request.owner_id,
+                item_id: item.id,
+                include_trashed: false,
+            })
+            .await
+    }
+}
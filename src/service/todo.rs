@@ -1,64 +1,118 @@
 use crate::domain::{
-    CreateTodoItemRequest, Error, Filters, TodoCounter, TodoCreator, TodoGetter, TodoItem,
-    TodoLister, TodoListerAndCounter, TodoUpdater, UpdateTodoItemRequest,
+    CreateTodoItemRequest, DeleteTodoItemRequest, Error, Filters, GetTodoItemRequest,
+    ProjectRepository, RestoreTodoItemRequest, TodoCounter, TodoCreator, TodoDeleter, TodoGetter,
+    TodoItem, TodoLister, TodoListerAndCounter, TodoUpdater, UpdateTodoItemRequest,
 };
 
-pub struct TodoService<CR, CO, LI, GE, UP>
+const TITLE_MAX_LENGTH: usize = 100;
+
+fn validate_title(title: &str) -> Result<(), Error> {
+    if title.trim().is_empty() {
+        return Err(Error::validation("title", "must not be empty"));
+    }
+    if title.chars().count() > TITLE_MAX_LENGTH {
+        return Err(Error::validation(
+            "title",
+            format!("must be at most {} characters long", TITLE_MAX_LENGTH),
+        ));
+    }
+    Ok(())
+}
+
+pub struct TodoService<CR, CO, LI, GE, UP, DE, PR>
 where
     CR: TodoCreator,
     CO: TodoCounter,
     LI: TodoLister,
     GE: TodoGetter,
     UP: TodoUpdater,
+    DE: TodoDeleter,
+    PR: ProjectRepository,
 {
     creator: CR,
     counter: CO,
     lister: LI,
     getter: GE,
     updater: UP,
+    deleter: DE,
+    projects: PR,
 }
 
-impl<CR, CO, LI, GE, UP> TodoService<CR, CO, LI, GE, UP>
+impl<CR, CO, LI, GE, UP, DE, PR> TodoService<CR, CO, LI, GE, UP, DE, PR>
 where
     CR: TodoCreator,
     CO: TodoCounter,
     LI: TodoLister,
     GE: TodoGetter,
     UP: TodoUpdater,
+    DE: TodoDeleter,
+    PR: ProjectRepository,
 {
-    pub fn new(creator: CR, counter: CO, lister: LI, getter: GE, updater: UP) -> Self {
+    pub fn new(
+        creator: CR,
+        counter: CO,
+        lister: LI,
+        getter: GE,
+        updater: UP,
+        deleter: DE,
+        projects: PR,
+    ) -> Self {
         Self {
-            creator: creator,
-            counter: counter,
-            lister: lister,
-            getter: getter,
-            updater: updater,
+            creator,
+            counter,
+            lister,
+            getter,
+            updater,
+            deleter,
+            projects,
         }
     }
+
+    async fn check_project(&self, owner_id: i32, project_id: i32) -> Result<(), Error> {
+        let project = match self.projects.get(owner_id, project_id).await {
+            Ok(project) => project,
+            Err(Error::NotFound(_)) => {
+                return Err(Error::validation("project_id", "project not found"));
+            }
+            Err(err) => return Err(err),
+        };
+        if project.is_archived() {
+            return Err(Error::validation("project_id", "project is archived"));
+        }
+        Ok(())
+    }
 }
 
 #[async_trait]
-impl<CR, CO, LI, GE, UP> TodoCreator for TodoService<CR, CO, LI, GE, UP>
+impl<CR, CO, LI, GE, UP, DE, PR> TodoCreator for TodoService<CR, CO, LI, GE, UP, DE, PR>
 where
     CR: TodoCreator,
     CO: TodoCounter,
     LI: TodoLister,
     GE: TodoGetter,
     UP: TodoUpdater,
+    DE: TodoDeleter,
+    PR: ProjectRepository,
 {
     async fn create(&self, request: CreateTodoItemRequest) -> Result<TodoItem, Error> {
+        validate_title(&request.title)?;
+        if let Some(project_id) = request.project_id {
+            self.check_project(request.owner_id, project_id).await?;
+        }
         self.creator.create(request).await
     }
 }
 
 #[async_trait]
-impl<CR, CO, LI, GE, UP> TodoListerAndCounter for TodoService<CR, CO, LI, GE, UP>
+impl<CR, CO, LI, GE, UP, DE, PR> TodoListerAndCounter for TodoService<CR, CO, LI, GE, UP, DE, PR>
 where
     CR: TodoCreator,
     CO: TodoCounter,
     LI: TodoLister,
     GE: TodoGetter,
     UP: TodoUpdater,
+    DE: TodoDeleter,
+    PR: ProjectRepository,
 {
     async fn list(&self, filters: &Filters) -> Result<(Vec<TodoItem>, i64), Error> {
         let total = self.counter.count(filters).await?;
@@ -68,44 +122,115 @@ where
 }
 
 #[async_trait]
-impl<CR, CO, LI, GE, UP> TodoGetter for TodoService<CR, CO, LI, GE, UP>
+impl<CR, CO, LI, GE, UP, DE, PR> TodoGetter for TodoService<CR, CO, LI, GE, UP, DE, PR>
 where
     CR: TodoCreator,
     CO: TodoCounter,
     LI: TodoLister,
     GE: TodoGetter,
     UP: TodoUpdater,
+    DE: TodoDeleter,
+    PR: ProjectRepository,
 {
-    async fn one(&self, id: i32) -> Result<TodoItem, Error> {
-        self.getter.one(id).await
+    async fn one(&self, request: GetTodoItemRequest) -> Result<TodoItem, Error> {
+        self.getter.one(request).await
     }
 }
+
 #[async_trait]
-impl<CR, CO, LI, GE, UP> TodoUpdater for TodoService<CR, CO, LI, GE, UP>
+impl<CR, CO, LI, GE, UP, DE, PR> TodoUpdater for TodoService<CR, CO, LI, GE, UP, DE, PR>
 where
     CR: TodoCreator,
     CO: TodoCounter,
     LI: TodoLister,
     GE: TodoGetter,
     UP: TodoUpdater,
+    DE: TodoDeleter,
+    PR: ProjectRepository,
 {
     async fn update(&self, request: UpdateTodoItemRequest) -> Result<TodoItem, Error> {
-        let stored = self.getter.one(request.item_id).await?;
-        if stored.owner_id != request.owner_id {
-            return Err(Error::Forbidden(format!(
-                "owner is {}, but received request from {}",
-                stored.owner_id, request.owner_id,
-            )));
+        if request.is_empty() {
+            return Err(Error::OperationNotApplicable(
+                "nothing to update".to_string(),
+            ));
         }
 
-        if !stored.status.can_be_updated_to(&request.status) {
+        if let Some(title) = &request.title {
+            validate_title(title)?;
+        }
+
+        let stored = self
+            .one(GetTodoItemRequest {
+                owner_id: request.owner_id,
+                item_id: request.item_id,
+                include_trashed: false,
+            })
+            .await?;
+
+        if let Some(Some(project_id)) = request.project_id
+            && stored.project_id != Some(project_id)
+        {
+            self.check_project(request.owner_id, project_id).await?;
+        }
+
+        if let Some(status) = &request.status
+            && *status != stored.status
+            && !stored.status.can_be_updated_to(status)
+        {
             return Err(Error::OperationNotApplicable(format!(
                 "can't update from {} to {}",
-                stored.status.to_string(),
-                request.status.to_string()
+                stored.status, status
             )));
         }
 
         self.updater.update(request).await
     }
 }
+
+#[async_trait]
+impl<CR, CO, LI, GE, UP, DE, PR> TodoDeleter for TodoService<CR, CO, LI, GE, UP, DE, PR>
+where
+    CR: TodoCreator,
+    CO: TodoCounter,
+    LI: TodoLister,
+    GE: TodoGetter,
+    UP: TodoUpdater,
+    DE: TodoDeleter,
+    PR: ProjectRepository,
+{
+    async fn delete(&self, request: DeleteTodoItemRequest) -> Result<TodoItem, Error> {
+        let stored = self
+            .one(GetTodoItemRequest {
+                owner_id: request.owner_id,
+                item_id: request.item_id,
+                include_trashed: true,
+            })
+            .await?;
+
+        if stored.deleted_at.is_some() {
+            return Err(Error::OperationNotApplicable(
+                "item is already in trash".to_string(),
+            ));
+        }
+
+        self.deleter.delete(request).await
+    }
+
+    async fn restore(&self, request: RestoreTodoItemRequest) -> Result<TodoItem, Error> {
+        let stored = self
+            .one(GetTodoItemRequest {
+                owner_id: request.owner_id,
+                item_id: request.item_id,
+                include_trashed: true,
+            })
+            .await?;
+
+        if stored.deleted_at.is_none() {
+            return Err(Error::OperationNotApplicable(
+                "item is not in trash".to_string(),
+            ));
+        }
+
+        self.deleter.restore(request).await
+    }
+}
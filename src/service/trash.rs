@@ -0,0 +1,35 @@
+use crate::domain::TodoPurger;
+
+pub struct TrashPurgeJob<P>
+where
+    P: TodoPurger,
+{
+    purger: P,
+    retention: time::Duration,
+    interval: std::time::Duration,
+}
+
+impl<P> TrashPurgeJob<P>
+where
+    P: TodoPurger,
+{
+    pub fn new(purger: P, retention: time::Duration, interval: std::time::Duration) -> Self {
+        Self {
+            purger,
+            retention,
+            interval,
+        }
+    }
+
+    pub async fn run(self) {
+        let mut ticker = rocket::tokio::time::interval(self.interval);
+        loop {
+            ticker.tick().await;
+            match self.purger.purge(self.retention).await {
+                Ok(0) => {}
+                Ok(purged) => info!("Purged {} todo items from trash.", purged),
+                Err(err) => error!("Failed to purge todo items from trash: {:?}", err),
+            }
+        }
+    }
+}